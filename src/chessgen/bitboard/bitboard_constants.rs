@@ -167,6 +167,52 @@ impl BitBoard {
     #[rustfmt::skip]
     pub const RANK_8: BitBoard = BitBoard::from_index_array(&[Index::A8, Index::B8, Index::C8, Index::D8, Index::E8, Index::F8, Index::G8, Index::H8]);
 
+    /// Board's light squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::BitBoard;
+    ///
+    /// assert_eq!(
+    ///     BitBoard::from_string("
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///     ").unwrap(),
+    ///     BitBoard::LIGHT_SQUARES
+    ///  );
+    /// ```
+    pub const LIGHT_SQUARES: BitBoard = BitBoard::new(0x55AA55AA55AA55AA);
+
+    /// Board's dark squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::BitBoard;
+    ///
+    /// assert_eq!(
+    ///     BitBoard::from_string("
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///         - x - x - x - x
+    ///         x - x - x - x -
+    ///     ").unwrap(),
+    ///     BitBoard::DARK_SQUARES
+    ///  );
+    /// ```
+    pub const DARK_SQUARES: BitBoard = BitBoard::new(!BitBoard::LIGHT_SQUARES.state);
+
     /// Board's A1H8 diagonals.
     ///
     /// # Examples
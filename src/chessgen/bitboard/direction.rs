@@ -0,0 +1,100 @@
+use std::ops::Deref;
+
+/// Direction of a move on the board, used by [crate::BitBoard::ray()].
+/// Every direction is followed by its opposite direction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(usize)]
+pub enum Direction {
+    /// Towards rank 8.
+    North = 0,
+    /// Towards rank 1.
+    South,
+    /// Towards file H.
+    East,
+    /// Towards file A.
+    West,
+    /// Towards H8.
+    NorthEast,
+    /// Towards A1.
+    SouthWest,
+    /// Towards H1.
+    SouthEast,
+    /// Towards A8.
+    NorthWest,
+}
+
+// Dereference Direction into usize
+impl Deref for Direction {
+    type Target = usize;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl Direction {
+    /// Array of possible Direction values.
+    pub const VALUES: [Direction; 8] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+        Direction::NorthEast,
+        Direction::SouthWest,
+        Direction::SouthEast,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the opposite direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Direction;
+    ///
+    /// assert_eq!(Direction::North.opposite(), Direction::South);
+    /// assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+    /// ```
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        Direction::VALUES[self as usize ^ 1]
+    }
+
+    /// Returns (file, rank) step of the direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Direction;
+    ///
+    /// assert_eq!(Direction::NorthWest.delta(), (-1, 1));
+    /// ```
+    #[must_use]
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::South => (0, -1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+            Direction::NorthEast => (1, 1),
+            Direction::SouthWest => (-1, -1),
+            Direction::SouthEast => (1, -1),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+
+    /// Checks if a rook moves in the direction, otherwise a bishop does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Direction;
+    ///
+    /// assert!(Direction::West.is_orthogonal());
+    /// assert!(!Direction::NorthEast.is_orthogonal());
+    /// ```
+    #[must_use]
+    pub const fn is_orthogonal(self) -> bool {
+        (self as usize) < 4
+    }
+}
@@ -238,6 +238,38 @@ impl Index {
             Some(Index::new(self.index + 8))
         }
     }
+
+    /// Returns Index mirrored vertically, rank 1 becomes rank 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Index;
+    ///
+    /// assert_eq!(Index::C2.mirrored_vertically(), Index::C7);
+    /// assert_eq!(Index::H8.mirrored_vertically(), Index::H1);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn mirrored_vertically(self) -> Index {
+        Index::new(self.index ^ 56)
+    }
+
+    /// Returns Index mirrored horizontally, file A becomes file H.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Index;
+    ///
+    /// assert_eq!(Index::C2.mirrored_horizontally(), Index::F2);
+    /// assert_eq!(Index::H8.mirrored_horizontally(), Index::A8);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn mirrored_horizontally(self) -> Index {
+        Index::new(self.index ^ 7)
+    }
 }
 
 /// Display and to_string() for an Index.
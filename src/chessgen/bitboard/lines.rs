@@ -0,0 +1,154 @@
+use super::{BitBoard, Direction, Index};
+
+/// Table of bitboards for every field and direction: [index][direction].
+type RaysTable = [[BitBoard; Direction::VALUES.len()]; Index::ALL_FIELDS.len()];
+
+/// Table of bitboards for every pair of fields: [index][index].
+type LinesTable = [[BitBoard; Index::ALL_FIELDS.len()]; Index::ALL_FIELDS.len()];
+
+/// Computes rays from every field in every direction.
+const fn rays() -> RaysTable {
+    let mut rays = [[BitBoard::EMPTY; Direction::VALUES.len()]; Index::ALL_FIELDS.len()];
+
+    let mut i = 0;
+    while i < Index::ALL_FIELDS.len() {
+        let mut d = 0;
+        while d < Direction::VALUES.len() {
+            let (dx, dy) = Direction::VALUES[d].delta();
+
+            let mut ray = BitBoard::EMPTY;
+            let mut b = Index::new(i).as_bitboard().shifted(dx, dy);
+            while b.state != 0 {
+                ray = BitBoard::new(ray.state | b.state);
+                b = b.shifted(dx, dy);
+            }
+            rays[i][d] = ray;
+
+            d += 1;
+        }
+        i += 1;
+    }
+
+    rays
+}
+
+/// Computes lines between all pairs of fields:
+/// fields strictly between them if `between` is set, whole line crossing them otherwise.
+const fn lines(between: bool) -> LinesTable {
+    let rays = rays();
+    let mut lines = [[BitBoard::EMPTY; Index::ALL_FIELDS.len()]; Index::ALL_FIELDS.len()];
+
+    let mut i = 0;
+    while i < Index::ALL_FIELDS.len() {
+        let mut d = 0;
+        while d < Direction::VALUES.len() {
+            let opposite = Direction::VALUES[d].opposite() as usize;
+
+            let mut b = rays[i][d];
+            while let Some(t) = b.bitscan() {
+                b = BitBoard::new(b.state & (b.state - 1));
+                lines[i][t.index] = if between {
+                    BitBoard::new(rays[i][d].state & rays[t.index][opposite].state)
+                } else {
+                    BitBoard::new(
+                        rays[i][d].state
+                            | rays[i][opposite].state
+                            | Index::new(i).as_bitboard().state,
+                    )
+                };
+            }
+
+            d += 1;
+        }
+        i += 1;
+    }
+
+    lines
+}
+
+impl BitBoard {
+    /// Rays from every field to the edge of the board: `[index][direction]`.
+    /// The field itself is not part of its rays.
+    pub const RAYS: &'static RaysTable = &rays();
+
+    /// Fields strictly between two fields on the same rank, file or diagonal: `[index][index]`.
+    /// Empty for fields which are not on the same line.
+    pub const BETWEEN: &'static LinesTable = &lines(true);
+
+    /// Whole lines from edge to edge crossing two fields on the same rank, file or diagonal: `[index][index]`.
+    /// Empty for fields which are not on the same line.
+    pub const LINE: &'static LinesTable = &lines(false);
+
+    /// Returns ray from a field to the edge of the board in a direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Direction, Index};
+    ///
+    /// assert_eq!(
+    ///     BitBoard::ray(Index::C3, Direction::NorthEast),
+    ///     BitBoard::from_string("
+    ///         - - - - - - - x
+    ///         - - - - - - x -
+    ///         - - - - - x - -
+    ///         - - - - x - - -
+    ///         - - - x - - - -
+    ///         - - - - - - - -
+    ///         - - - - - - - -
+    ///         - - - - - - - -
+    ///     ").unwrap()
+    /// );
+    ///
+    /// assert_eq!(BitBoard::ray(Index::A1, Direction::West), BitBoard::EMPTY);
+    /// assert_eq!(BitBoard::ray(Index::A1, Direction::North), BitBoard::FILE_A ^ Index::A1);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn ray(i: Index, direction: Direction) -> BitBoard {
+        BitBoard::RAYS[i.index][direction as usize]
+    }
+
+    /// Returns fields strictly between two fields on the same rank, file or diagonal.
+    /// Returns empty BitBoard for fields which are not on the same line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Index};
+    ///
+    /// assert_eq!(BitBoard::between(Index::B2, Index::E5), Index::C3 | Index::D4);
+    /// assert_eq!(BitBoard::between(Index::E5, Index::B2), Index::C3 | Index::D4);
+    /// assert_eq!(BitBoard::between(Index::A1, Index::A2), BitBoard::EMPTY);
+    /// assert_eq!(BitBoard::between(Index::A1, Index::B3), BitBoard::EMPTY);
+    /// assert_eq!(
+    ///     BitBoard::between(Index::A1, Index::H1),
+    ///     BitBoard::RANK_1 ^ Index::A1 ^ Index::H1
+    /// );
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn between(a: Index, b: Index) -> BitBoard {
+        BitBoard::BETWEEN[a.index][b.index]
+    }
+
+    /// Returns whole line from edge to edge crossing two fields on the same rank, file or diagonal.
+    /// Returns empty BitBoard for fields which are not on the same line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Index};
+    ///
+    /// assert_eq!(BitBoard::line(Index::B2, Index::E5), BitBoard::A1H8[7]);
+    /// assert_eq!(BitBoard::line(Index::B7, Index::G2), BitBoard::A8H1[7]);
+    /// assert_eq!(BitBoard::line(Index::A3, Index::A5), BitBoard::FILE_A);
+    /// assert_eq!(BitBoard::line(Index::A1, Index::B3), BitBoard::EMPTY);
+    /// assert_eq!(BitBoard::line(Index::A1, Index::A1), BitBoard::EMPTY);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn line(a: Index, b: Index) -> BitBoard {
+        BitBoard::LINE[a.index][b.index]
+    }
+}
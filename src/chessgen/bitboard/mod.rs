@@ -1,4 +1,5 @@
 pub use self::bitboard::BitBoard;
+pub use self::direction::Direction;
 pub use self::error::InvalidBitBoardStringError;
 pub use self::error::InvalidIndexNotationError;
 pub use self::index::Index;
@@ -6,7 +7,9 @@ pub use self::index::Index;
 #[allow(clippy::module_inception)]
 mod bitboard;
 mod bitboard_constants;
+mod direction;
 mod error;
 mod index;
 mod index_constants;
+mod lines;
 mod operators;
@@ -0,0 +1,192 @@
+use crate::{BitBoard, Index};
+
+use super::{ChessBoard, Color, InvalidChessBoardError, Piece};
+
+/// Builder of a valid ChessBoard.
+///
+/// Pieces, side to move, castling options, en-passant target and move clocks are collected
+/// and [ChessBoardBuilder::build()] constructs the board and checks it by [ChessBoard::validate()].
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, ChessBoardBuilder, Color, Index, Piece};
+///
+/// let board = ChessBoardBuilder::new()
+///     .piece(Index::E1, Color::White, Piece::King)
+///     .piece(Index::H1, Color::White, Piece::Rook)
+///     .piece(Index::E4, Color::White, Piece::Pawn)
+///     .piece(Index::E8, Color::Black, Piece::King)
+///     .next_move(Color::Black)
+///     .castling(Color::White, Piece::King, Some(Index::H1))
+///     .en_passant_target(Some(Index::E3))
+///     .full_move_number(12)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(board.to_fen(), "4k3/8/8/8/4P3/8/8/4K2R b K e3 0 12");
+///
+/// // Chess960 castling rook
+/// let board = ChessBoardBuilder::from_board(&board)
+///     .piece(Index::G1, Color::White, Piece::Rook)
+///     .castling(Color::White, Piece::King, Some(Index::G1))
+///     .en_passant_target(None)
+///     .build()
+///     .unwrap();
+///
+/// assert!(board.is_chess960());
+/// assert_eq!(board.to_shredder_fen(), "4k3/8/8/8/4P3/8/8/4K1RR b G - 0 12");
+///
+/// // black king is missing
+/// assert!(ChessBoardBuilder::new().piece(Index::E1, Color::White, Piece::King).build().is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ChessBoardBuilder {
+    pieces: [[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()],
+    next_move: Color,
+    castling_rooks: [[Option<Index>; 2]; Color::VALUES.len()],
+    chess960: bool,
+    en_passant_target: Option<Index>,
+    half_move_clock: usize,
+    full_move_number: usize,
+}
+
+impl Default for ChessBoardBuilder {
+    fn default() -> Self {
+        ChessBoardBuilder::new()
+    }
+}
+
+impl ChessBoardBuilder {
+    /// Constructs a builder of an empty board with White to move and no castling options.
+    #[must_use]
+    pub const fn new() -> Self {
+        ChessBoardBuilder {
+            pieces: [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()],
+            next_move: Color::White,
+            castling_rooks: [[None; 2]; Color::VALUES.len()],
+            chess960: false,
+            en_passant_target: None,
+            half_move_clock: 0,
+            full_move_number: 1,
+        }
+    }
+
+    /// Constructs a builder initialized by a board.
+    #[must_use]
+    pub fn from_board(board: &ChessBoard) -> Self {
+        let mut castling_rooks = [[None; 2]; Color::VALUES.len()];
+        for c in Color::VALUES {
+            for side in [Piece::King, Piece::Queen] {
+                castling_rooks[*c][*side] = board.castling_rook(c, side);
+            }
+        }
+
+        ChessBoardBuilder {
+            pieces: board.pieces,
+            next_move: board.next_move,
+            castling_rooks,
+            chess960: board.is_chess960(),
+            en_passant_target: board.en_passant_target,
+            half_move_clock: board.half_move_clock,
+            full_move_number: board.full_move_number,
+        }
+    }
+
+    /// Places a piece on a field, replacing the piece on the field.
+    #[must_use]
+    pub fn piece(self, i: Index, color: Color, piece: Piece) -> Self {
+        let mut builder = self.remove_piece(i);
+        builder.pieces[*color][*piece] |= i;
+        builder
+    }
+
+    /// Removes a piece from a field.
+    #[must_use]
+    pub fn remove_piece(mut self, i: Index) -> Self {
+        for c in Color::VALUES {
+            for p in Piece::VALUES {
+                self.pieces[*c][*p] &= !i.as_bitboard();
+            }
+        }
+        self
+    }
+
+    /// Sets the side to move.
+    #[must_use]
+    pub const fn next_move(mut self, color: Color) -> Self {
+        self.next_move = color;
+        self
+    }
+
+    /// Sets castling option of a side (Piece::King or Piece::Queen)
+    /// to castle with the rook on the given field, None removes the option.
+    /// Castling with other than the corner rooks or with king out of the e-file
+    /// switches the board to Chess960 mode.
+    #[must_use]
+    pub fn castling(mut self, color: Color, side: Piece, rook: Option<Index>) -> Self {
+        self.castling_rooks[*color][*side] = rook;
+        self
+    }
+
+    /// Sets Chess960 (Fischer Random Chess) mode, see [ChessBoard::is_chess960()].
+    #[must_use]
+    pub const fn chess960(mut self, chess960: bool) -> Self {
+        self.chess960 = chess960;
+        self
+    }
+
+    /// Sets the en-passant target field.
+    #[must_use]
+    pub const fn en_passant_target(mut self, target: Option<Index>) -> Self {
+        self.en_passant_target = target;
+        self
+    }
+
+    /// Sets the number of half moves since the last capture or pawn move.
+    #[must_use]
+    pub const fn half_move_clock(mut self, half_move_clock: usize) -> Self {
+        self.half_move_clock = half_move_clock;
+        self
+    }
+
+    /// Sets the number of the full move.
+    #[must_use]
+    pub const fn full_move_number(mut self, full_move_number: usize) -> Self {
+        self.full_move_number = full_move_number;
+        self
+    }
+
+    /// Constructs the board, returns an error if it is not a valid chess position.
+    pub fn build(&self) -> Result<ChessBoard, InvalidChessBoardError> {
+        let mut castling_options = [[false; 2]; Color::VALUES.len()];
+        let mut castling_rooks = ChessBoard::STANDARD_CASTLING_ROOKS;
+        let mut chess960 = self.chess960;
+
+        for c in Color::VALUES {
+            let king = self.pieces[*c][*Piece::King].bitscan();
+            for side in [Piece::King, Piece::Queen] {
+                if let Some(rook) = self.castling_rooks[*c][*side] {
+                    castling_options[*c][*side] = true;
+                    castling_rooks[*c][*side] = rook;
+                    chess960 |= rook != ChessBoard::STANDARD_CASTLING_ROOKS[*c][*side]
+                        || king.is_some_and(|king| king.file() != 4);
+                }
+            }
+        }
+
+        let board = ChessBoard::from_parts(
+            self.pieces,
+            self.next_move,
+            castling_options,
+            castling_rooks,
+            chess960,
+            self.en_passant_target,
+            self.half_move_clock,
+            self.full_move_number,
+        );
+        board.validate()?;
+
+        Ok(board)
+    }
+}
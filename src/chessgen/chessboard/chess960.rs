@@ -0,0 +1,146 @@
+use crate::{BitBoard, Index};
+
+use super::{ChessBoard, Color, Piece};
+
+/// Placements of two knights on five free fields of a Chess960 start position.
+const KNIGHTS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+/// Chess960 (Fischer Random Chess).
+///
+/// Castling options are tied to files of the castling rooks, boards are read from
+/// and written to X-FEN and Shredder-FEN. Castling moves are encoded as king captures own rook.
+///
+/// See: [ChessProgramming Chess960](https://www.chessprogramming.org/Chess960)
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, PerfT};
+///
+/// // Chess960 PerfT results, see: https://www.chessprogramming.org/Chess960_Perft_Results
+/// let perft = PerfT::new(1024 * 1024);
+///
+/// for (fen, counts) in [
+///     ("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9", [21, 528, 12_189, 326_672]),
+///     ("2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9", [21, 807, 18_002, 667_366]),
+///     ("b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9", [20, 479, 10_471, 273_318]),
+///     ("qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9", [22, 593, 13_440, 382_958]),
+///     ("1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9", [28, 1_120, 31_058, 1_171_749]),
+///     ("qnbnr1kr/ppp1b1pp/4p3/3p1p2/8/2NPP3/PPP1BPPP/QNB1R1KR w HEhe - 1 9", [29, 899, 26_578, 824_055]),
+///     ("q1bnrkr1/ppppp2p/2n2p2/4b1p1/2NP4/8/PPP1PPPP/QNB1RRKB w ge - 1 9", [30, 860, 24_566, 732_757]),
+///     ("qbn1brkr/ppp1p1p1/2n4p/3p1p2/P7/6PP/QPPPPP2/1BNNBRKR w HFhf - 0 9", [25, 635, 17_054, 465_806]),
+///     ("qnnbbrkr/1p2ppp1/2pp3p/p7/1P5P/2NP4/P1P1PPP1/Q1NBBRKR w HFhf - 0 9", [24, 572, 15_243, 384_260]),
+///     ("qn1rbbkr/ppp2p1p/1n1pp1p1/8/3P4/P6P/1PP1PPPK/QNNRBB1R w hd - 2 9", [28, 811, 23_175, 679_699]),
+/// ] {
+///     let board = ChessBoard::from_fen(fen).unwrap();
+///     assert!(board.is_chess960());
+///     assert_eq!(board.to_shredder_fen(), fen);
+///
+///     for (depth, count) in counts.iter().enumerate() {
+///         assert_eq!(perft.perft_n(&board, depth + 1), *count, "{} {}", fen, depth + 1);
+///     }
+/// }
+/// ```
+impl ChessBoard {
+    /// Number of Chess960 start positions.
+    pub const CHESS960_POSITIONS: usize = 960;
+
+    /// Returns Chess960 start position by its index (Scharnagl numbering),
+    /// None if the index is not less than [ChessBoard::CHESS960_POSITIONS].
+    /// Position 518 is the standard layout.
+    ///
+    /// See: [Chess960 numbering scheme](https://www.chessprogramming.org/Reinhard_Scharnagl#Chess960Numbering)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert_eq!(
+    ///     ChessBoard::chess960(0).unwrap().to_fen(),
+    ///     "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1"
+    /// );
+    /// assert_eq!(
+    ///     ChessBoard::chess960(518).unwrap().to_fen(),
+    ///     ChessBoard::STANDARD_BOARD_FEN
+    /// );
+    /// assert_eq!(
+    ///     ChessBoard::chess960(959).unwrap().to_shredder_fen(),
+    ///     "rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w CAca - 0 1"
+    /// );
+    /// assert!(ChessBoard::chess960(960).is_none());
+    ///
+    /// let board = ChessBoard::chess960(518).unwrap();
+    /// assert!(board.is_chess960());
+    /// assert_eq!(board.legal_moves(), ChessBoard::STANDARD.legal_moves());
+    /// ```
+    #[must_use]
+    pub fn chess960(index: usize) -> Option<Self> {
+        if index >= ChessBoard::CHESS960_POSITIONS {
+            return None;
+        }
+
+        let mut files: [Option<Piece>; 8] = [None; 8];
+        let mut n = index;
+
+        // bishops on fields of different colors
+        files[(n % 4) * 2 + 1] = Some(Piece::Bishop);
+        n /= 4;
+        files[(n % 4) * 2] = Some(Piece::Bishop);
+        n /= 4;
+
+        // queen, knights, and rook, king, rook on the remaining free files
+        let mut place = |piece: Piece, mut nth: usize| {
+            for file in files.iter_mut().filter(|f| f.is_none()) {
+                if nth == 0 {
+                    *file = Some(piece);
+                    return;
+                }
+                nth -= 1;
+            }
+        };
+
+        place(Piece::Queen, n % 6);
+        n /= 6;
+        let (first, second) = KNIGHTS[n];
+        place(Piece::Knight, second);
+        place(Piece::Knight, first);
+        place(Piece::Rook, 0);
+        place(Piece::King, 0);
+        place(Piece::Rook, 0);
+
+        let mut pieces = [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()];
+        for (file, piece) in files.iter().enumerate() {
+            let piece = piece.unwrap();
+            pieces[*Color::White][*piece] |= Index::from_rank_and_file(0, file);
+            pieces[*Color::Black][*piece] |= Index::from_rank_and_file(7, file);
+        }
+        pieces[*Color::White][*Piece::Pawn] = BitBoard::RANK_1.shifted_north();
+        pieces[*Color::Black][*Piece::Pawn] = BitBoard::RANK_8.shifted_south();
+
+        let (castling_options, castling_rooks, _) =
+            ChessBoard::castling_from_fen(&pieces, "KQkq").unwrap();
+
+        Some(ChessBoard::from_parts(
+            pieces,
+            Color::White,
+            castling_options,
+            castling_rooks,
+            true,
+            None,
+            0,
+            1,
+        ))
+    }
+}
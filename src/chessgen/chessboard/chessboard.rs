@@ -1,28 +1,41 @@
 use std::fmt;
 use std::fmt::Write;
 
-use crate::{BitBoard, Generator, IllegalMoveError, Index};
+use crate::{BitBoard, Direction, Generator, IllegalMoveError, Index, Zobrist};
 
-use super::{Color, InvalidChessBoardStringError, InvalidFENStringError, Move, Piece};
+use super::{
+    Color, InvalidChessBoardStringError, InvalidFENStringError, Move, MoveGeneration, MoveKind,
+    MoveList, Outcome, Piece, UndoInfo,
+};
 
 /// ChessBoard representation.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+///
+/// Fields are read by accessors and changed by moves or by controlled mutators,
+/// which keep the cached pieces and the hash in sync.
+#[derive(Debug, Copy, Clone)]
 pub struct ChessBoard {
     /// BitBoards array of pieces.
-    pub pieces: [[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()],
+    pub(crate) pieces: [[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()],
     /// Color to move.
-    pub next_move: Color,
+    pub(crate) next_move: Color,
     /// Boolean array of castling options.
     /// Only [*Piece::King] and [*Piece::Queen] is used.
-    pub castling_options: [[bool; 2]; Color::VALUES.len()],
+    pub(crate) castling_options: [[bool; 2]; Color::VALUES.len()],
+    /// Rooks castling with the king: [color][*Piece::King or *Piece::Queen].
+    /// Valid only if the castling option is set.
+    castling_rooks: [[Index; 2]; Color::VALUES.len()],
+    /// Chess960 mode, castling moves are encoded as king captures rook.
+    chess960: bool,
     /// En-Passant target or none.
-    pub en_passant_target: Option<Index>,
+    pub(crate) en_passant_target: Option<Index>,
     /// Half move clock.
-    pub half_move_clock: usize,
+    pub(crate) half_move_clock: usize,
     /// Full move number.
-    pub full_move_number: usize,
+    pub(crate) full_move_number: usize,
     /// Cached union of all pieces per color for fast access.
     color_pieces: [BitBoard; Color::VALUES.len()],
+    /// Zobrist hash, updated incrementally.
+    hash: u64,
 }
 
 /// Constructs a new empty ChessBoard.
@@ -32,6 +45,28 @@ impl Default for ChessBoard {
     }
 }
 
+/// Equality of ChessBoards.
+/// Castling rooks are compared only for the sides with castling option,
+/// cached pieces and the hash are derived from the compared fields.
+impl PartialEq for ChessBoard {
+    fn eq(&self, other: &Self) -> bool {
+        self.pieces == other.pieces
+            && self.next_move == other.next_move
+            && self.castling_options == other.castling_options
+            && Color::VALUES.iter().all(|c| {
+                [Piece::King, Piece::Queen]
+                    .iter()
+                    .all(|side| self.castling_rook(*c, *side) == other.castling_rook(*c, *side))
+            })
+            && self.chess960 == other.chess960
+            && self.en_passant_target == other.en_passant_target
+            && self.half_move_clock == other.half_move_clock
+            && self.full_move_number == other.full_move_number
+    }
+}
+
+impl Eq for ChessBoard {}
+
 impl ChessBoard {
     // FEN definition of standard chessboard layout.
     ///
@@ -100,6 +135,10 @@ impl ChessBoard {
     /// ```
     pub const STANDARD: ChessBoard = ChessBoard::new_standard_board();
 
+    /// Castling rooks of the standard layout: [color][*Piece::King or *Piece::Queen].
+    pub(super) const STANDARD_CASTLING_ROOKS: [[Index; 2]; Color::VALUES.len()] =
+        [[Index::H1, Index::A1], [Index::H8, Index::A8]];
+
     /// Constructs a new empty ChessBoard.
     ///
     /// # Examples
@@ -114,15 +153,20 @@ impl ChessBoard {
     /// ```
     #[must_use]
     const fn new() -> Self {
-        ChessBoard {
+        let mut board = ChessBoard {
             pieces: [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()],
             next_move: Color::White,
             castling_options: [[false; 2]; Color::VALUES.len()],
+            castling_rooks: ChessBoard::STANDARD_CASTLING_ROOKS,
+            chess960: false,
             en_passant_target: None,
             half_move_clock: 0,
             full_move_number: 1,
             color_pieces: [BitBoard::EMPTY; Color::VALUES.len()],
-        }
+            hash: 0,
+        };
+        board.hash = Zobrist::Z.hash(&board);
+        board
     }
 
     /// Creates a new chessboard initialized to the standard layout.
@@ -198,15 +242,20 @@ impl ChessBoard {
         castling_options[Color::Black as usize][Piece::Queen as usize] = true;
         castling_options[Color::Black as usize][Piece::King as usize] = true;
 
-        ChessBoard {
+        let mut board = ChessBoard {
             pieces,
             next_move: Color::White,
             castling_options,
+            castling_rooks: ChessBoard::STANDARD_CASTLING_ROOKS,
+            chess960: false,
             en_passant_target: None,
             half_move_clock: 0,
             full_move_number: 1,
             color_pieces: ChessBoard::compute_color_pieces(&pieces),
-        }
+            hash: 0,
+        };
+        board.hash = Zobrist::Z.hash(&board);
+        board
     }
 
     /// Compute cached union of pieces per color from the piece bitboards.
@@ -254,6 +303,214 @@ impl ChessBoard {
         self.color_pieces[*color]
     }
 
+    /// Returns bitboard of pieces of a color and a kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, Piece};
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.pieces_of(Color::Black, Piece::Queen), Index::D8.as_bitboard());
+    /// assert_eq!(ChessBoard::STANDARD.pieces_of(Color::White, Piece::Rook), Index::A1 | Index::H1);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn pieces_of(&self, color: Color, piece: Piece) -> BitBoard {
+        self.pieces[*color][*piece]
+    }
+
+    /// Returns bitboards of all pieces: `[color][piece]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, Piece};
+    ///
+    /// let pieces = ChessBoard::STANDARD.piece_bitboards();
+    /// assert_eq!(pieces[*Color::White][*Piece::King], Index::E1.as_bitboard());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn piece_bitboards(&self) -> &[[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()] {
+        &self.pieces
+    }
+
+    /// Returns color to move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Move};
+    ///
+    /// let board = ChessBoard::STANDARD;
+    /// assert_eq!(board.next_move(), Color::White);
+    /// assert_eq!(board.apply_move(&Move::from_string("e2e4").unwrap()).next_move(), Color::Black);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn next_move(&self) -> Color {
+        self.next_move
+    }
+
+    /// Checks if a color can castle on a side (Piece::King or Piece::Queen),
+    /// see [ChessBoard::castling_rook()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Piece};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w Kq - 0 1").unwrap();
+    /// assert!(board.castling_option(Color::White, Piece::King));
+    /// assert!(!board.castling_option(Color::White, Piece::Queen));
+    /// assert!(!board.castling_option(Color::Black, Piece::King));
+    /// assert!(board.castling_option(Color::Black, Piece::Queen));
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn castling_option(&self, color: Color, side: Piece) -> bool {
+        self.castling_options[*color][*side]
+    }
+
+    /// Returns en-passant target, field skipped by the last double pawn push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Index, Move};
+    ///
+    /// let board = ChessBoard::STANDARD;
+    /// assert_eq!(board.en_passant_target(), None);
+    /// assert_eq!(board.apply_move(&Move::from_string("e2e4").unwrap()).en_passant_target(), Some(Index::E3));
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn en_passant_target(&self) -> Option<Index> {
+        self.en_passant_target
+    }
+
+    /// Returns number of half moves since the last capture or pawn move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert_eq!(ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 12 40").unwrap().half_move_clock(), 12);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn half_move_clock(&self) -> usize {
+        self.half_move_clock
+    }
+
+    /// Returns number of the full move, starting at 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert_eq!(ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 12 40").unwrap().full_move_number(), 40);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn full_move_number(&self) -> usize {
+        self.full_move_number
+    }
+
+    /// Sets color to move and updates the hash.
+    /// Use [ChessBoard::validate()] to check the resulting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color};
+    ///
+    /// let mut board = ChessBoard::STANDARD;
+    /// board.set_next_move(Color::Black);
+    /// assert_eq!(board, ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").unwrap());
+    /// ```
+    pub fn set_next_move(&mut self, color: Color) {
+        if self.next_move != color {
+            self.next_move = color;
+            self.hash ^= Zobrist::Z.side();
+        }
+    }
+
+    /// Sets castling option of a color on a side (Piece::King or Piece::Queen) and updates the hash.
+    /// The option refers to the current castling rook of the side, the corner rook by default.
+    /// Use [ChessBoard::validate()] to check the resulting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Piece};
+    ///
+    /// let mut board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1").unwrap();
+    /// board.set_castling_option(Color::White, Piece::Queen, true);
+    /// board.set_castling_option(Color::Black, Piece::King, true);
+    /// assert_eq!(board, ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w Qk - 0 1").unwrap());
+    /// ```
+    pub fn set_castling_option(&mut self, color: Color, side: Piece, enabled: bool) {
+        if self.castling_options[*color][*side] != enabled {
+            self.castling_options[*color][*side] = enabled;
+            self.hash ^= Zobrist::Z.castling(color, side, self.castling_rooks[*color][*side]);
+        }
+    }
+
+    /// Sets en-passant target and updates the hash.
+    /// Use [ChessBoard::validate()] to check the resulting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Index};
+    ///
+    /// let mut board = ChessBoard::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - - 0 1").unwrap();
+    /// board.set_en_passant_target(Some(Index::E3));
+    /// assert_eq!(board, ChessBoard::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1").unwrap());
+    /// ```
+    pub fn set_en_passant_target(&mut self, target: Option<Index>) {
+        if let Some(i) = self.en_passant_target {
+            self.hash ^= Zobrist::Z.en_passant(i);
+        }
+        if let Some(i) = target {
+            self.hash ^= Zobrist::Z.en_passant(i);
+        }
+        self.en_passant_target = target;
+    }
+
+    /// Sets number of half moves since the last capture or pawn move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let mut board = ChessBoard::STANDARD;
+    /// board.set_half_move_clock(7);
+    /// assert_eq!(board.half_move_clock(), 7);
+    /// ```
+    pub fn set_half_move_clock(&mut self, half_move_clock: usize) {
+        self.half_move_clock = half_move_clock;
+    }
+
+    /// Sets number of the full move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let mut board = ChessBoard::STANDARD;
+    /// board.set_full_move_number(20);
+    /// assert_eq!(board.full_move_number(), 20);
+    /// ```
+    pub fn set_full_move_number(&mut self, full_move_number: usize) {
+        self.full_move_number = full_move_number;
+    }
+
     /// Returns bitboard of all pieces.
     ///
     /// # Examples
@@ -464,12 +721,65 @@ impl ChessBoard {
         None
     }
 
+    /// Places a piece on a ChessBoard field, replacing the piece on the field.
+    /// Cached pieces and the hash are kept in sync, castling options and en-passant target
+    /// are not changed. Use [ChessBoard::validate()] to check the resulting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, Piece};
+    ///
+    /// let mut board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    /// board.set_piece(Index::D2, Color::White, Piece::Pawn);
+    /// board.set_piece(Index::E2, Color::Black, Piece::Queen);
+    /// board.set_piece(Index::E2, Color::Black, Piece::Rook);
+    ///
+    /// assert_eq!(board, ChessBoard::from_fen("4k3/8/8/8/8/8/3Pr3/4K3 w - - 0 1").unwrap());
+    /// assert_eq!(board.piece_at(Index::E2), Some((Color::Black, Piece::Rook)));
+    /// ```
+    pub fn set_piece(&mut self, i: Index, color: Color, piece: Piece) {
+        self.remove_piece(i);
+
+        self.pieces[*color][*piece] |= i;
+        self.color_pieces[*color] |= i;
+        self.hash ^= Zobrist::Z.piece(color, piece, i);
+    }
+
+    /// Removes a piece from a ChessBoard field and returns it, None if the field is empty.
+    /// Cached pieces and the hash are kept in sync, castling options and en-passant target
+    /// are not changed. Use [ChessBoard::validate()] to check the resulting position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, Piece};
+    ///
+    /// let mut board = ChessBoard::STANDARD;
+    ///
+    /// assert_eq!(board.remove_piece(Index::D1), Some((Color::White, Piece::Queen)));
+    /// assert_eq!(board.remove_piece(Index::D1), None);
+    /// assert_eq!(
+    ///     board,
+    ///     ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNB1KBNR w KQkq - 0 1").unwrap()
+    /// );
+    /// ```
+    pub fn remove_piece(&mut self, i: Index) -> Option<(Color, Piece)> {
+        let (color, piece) = self.piece_at(i)?;
+
+        self.pieces[*color][*piece] ^= i;
+        self.color_pieces[*color] ^= i;
+        self.hash ^= Zobrist::Z.piece(color, piece, i);
+
+        Some((color, piece))
+    }
+
     /// Generate all legal moves for a board
     ///
     /// # Examples
     ///
     /// ```
-    /// use chessgen::{ChessBoard, Move};
+    /// use chessgen::{ChessBoard, Index, Move};
     ///
     /// let mut board = ChessBoard::from_string("
     ///       a b c d e f g h
@@ -486,11 +796,120 @@ impl ChessBoard {
     ///
     /// let moves = board.legal_moves();
     /// assert_eq!(moves.len(), 20);
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// // en-passant capture would expose the king to the rook
+    /// let board = ChessBoard::from_fen("8/8/8/KPp4r/8/8/8/4k3 w - c6 0 1").unwrap();
+    /// assert!(!board.legal_moves().contains(&m("b5c6")));
+    /// assert!(board.legal_moves().contains(&m("b5b6")));
+    ///
+    /// // pinned knight can not move, pinned bishop moves along the pin
+    /// let board = ChessBoard::from_fen("4k3/4r3/8/b7/4N3/8/3B4/4K3 w - - 0 1").unwrap();
+    /// assert!(board.legal_moves().iter().all(|m| m.from != Index::E4));
+    /// assert_eq!(board.legal_moves().iter().filter(|m| m.from == Index::D2).count(), 3);
+    ///
+    /// // double check, only the king moves
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/1b6/8/8/R3K1r1 w - - 0 1").unwrap();
+    /// assert!(board.legal_moves().iter().all(|m| m.from == Index::E1));
     /// ```
-    pub fn legal_moves(&self) -> Vec<Move> {
+    pub fn legal_moves(&self) -> MoveList {
         Generator::G.legal_moves(self)
     }
 
+    /// Generate all legal moves for a board into a list, without any allocation.
+    /// The list is cleared first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, MoveList};
+    ///
+    /// let mut moves = MoveList::new();
+    ///
+    /// ChessBoard::STANDARD.legal_moves_into(&mut moves);
+    /// assert_eq!(moves.len(), 20);
+    ///
+    /// ChessBoard::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap().legal_moves_into(&mut moves);
+    /// assert!(moves.is_empty());
+    /// ```
+    pub fn legal_moves_into(&self, moves: &mut MoveList) {
+        Generator::G.legal_moves_into(self, moves)
+    }
+
+    /// Generates a subset of legal moves, see [MoveGeneration].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, MoveGeneration};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    ///
+    /// let mut captures = 0;
+    /// board.legal_moves_with(MoveGeneration::Captures, &mut |m| {
+    ///     assert!(m.is_capture());
+    ///     captures += 1;
+    /// });
+    /// assert_eq!(captures, 8);
+    /// ```
+    pub fn legal_moves_with(&self, generation: MoveGeneration, f: &mut impl FnMut(Move)) {
+        Generator::G.legal_moves_with(self, generation, f)
+    }
+
+    /// Generates a subset of legal moves into a new list, see [MoveGeneration].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, ChessBoard, MoveGeneration, Piece};
+    ///
+    /// for fen in [
+    ///     ChessBoard::STANDARD_BOARD_FEN,
+    ///     "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+    ///     "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+    ///     "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    ///     "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    ///     "4k3/8/8/8/4r3/8/3P4/R3K2R w KQ - 0 1",
+    ///     "3k4/8/8/8/8/8/3B4/3QK2R w K - 0 1",
+    /// ] {
+    ///     let board = ChessBoard::from_fen(fen).unwrap();
+    ///     let all = board.legal_moves_of(MoveGeneration::All);
+    ///     let captures = board.legal_moves_of(MoveGeneration::Captures);
+    ///     let quiets = board.legal_moves_of(MoveGeneration::Quiets);
+    ///     let evasions = board.legal_moves_of(MoveGeneration::Evasions);
+    ///     let checks = board.legal_moves_of(MoveGeneration::QuietChecks);
+    ///
+    ///     assert_eq!(all, board.legal_moves());
+    ///     assert_eq!(captures.len() + quiets.len(), all.len());
+    ///     assert!(all.iter().all(|m| captures.contains(m) != quiets.contains(m)));
+    ///     assert!(captures.iter().all(|m| m.is_capture() || m.promotion.is_some()));
+    ///
+    ///     let in_check = board.attacks(board.next_move().opponent())
+    ///         & board.pieces_of(board.next_move(), Piece::King)
+    ///         != BitBoard::EMPTY;
+    ///     if in_check {
+    ///         assert_eq!(evasions, all);
+    ///     } else {
+    ///         assert!(evasions.is_empty());
+    ///     }
+    ///
+    ///     let gives_check = |m: &chessgen::Move| {
+    ///         let new = board.apply_move(m);
+    ///         new.attacks(board.next_move()) & new.pieces_of(new.next_move(), Piece::King) != BitBoard::EMPTY
+    ///     };
+    ///     let expected: Vec<_> = quiets.iter().filter(|m| gives_check(m)).collect();
+    ///     assert_eq!(checks.len(), expected.len());
+    ///     assert!(expected.iter().all(|m| checks.contains(m)));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn legal_moves_of(&self, generation: MoveGeneration) -> MoveList {
+        let mut moves = MoveList::new();
+        self.legal_moves_with(generation, &mut |m| moves.push(m));
+        moves
+    }
+
     /// Generates all pseudo-legal moves.
     ///
     /// See: [ChessProgramming Pseudo Legal Move](https://www.chessprogramming.org/Pseudo-Legal_Move)
@@ -521,6 +940,7 @@ impl ChessBoard {
     /// assert_eq!(board.legal_moves().len(), 16);
     /// ```
     pub fn moves(&self, f: &mut impl FnMut(Move)) {
+        self.debug_check_consistency();
         Generator::G.moves(self, f)
     }
 
@@ -599,93 +1019,373 @@ impl ChessBoard {
         Generator::G.is_opponent_king_under_check(self)
     }
 
-    /// Checks if BitMask is under attack by a side.
+    /// Checks if king of the side to move is under check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert!(!ChessBoard::STANDARD.is_check());
+    ///
+    /// let board = ChessBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").unwrap();
+    /// assert!(board.is_check());
+    /// assert!(!board.is_opponent_king_under_check());
+    /// ```
+    pub fn is_check(&self) -> bool {
+        self.is_my_king_under_check()
+    }
+
+    /// Returns pieces of a side attacking a field.
+    /// The field may be empty or occupied by a piece of any color.
     ///
     /// # Examples
     ///
     /// ```
     /// use chessgen::{BitBoard, ChessBoard, Color, Index};
     ///
-    /// let mut board = ChessBoard::from_string("
-    ///      - - - - - - - -
-    ///      - - - - - - - -
-    ///      - - - - - - - -
-    ///      - - - q k - - -
+    /// let board = ChessBoard::from_string("
+    ///      - - - - k - - -
     ///      - - - - - - - -
     ///      - - - - - - - -
+    ///      - - - r - - - -
     ///      - - - - - - - -
-    ///      - - - Q K - - -
+    ///      - - p - - - - -
+    ///      - - - P - - - -
+    ///      B - - Q K - - -
     /// ").unwrap();
     ///
-    ///
-    /// assert!(!board.is_bitmask_under_attack(Color::White, Index::A8 | Index::B8));
-    /// assert!(board.is_bitmask_under_attack(Color::White, Index::A4 | Index::A5));
+    /// assert_eq!(board.attackers_to(Index::C3, Color::White), Index::A1 | Index::D2);
+    /// assert_eq!(board.attackers_to(Index::D2, Color::White), Index::D1 | Index::E1);
+    /// assert_eq!(board.attackers_to(Index::D2, Color::Black), Index::C3 | Index::D5);
+    /// assert_eq!(board.attackers_to(Index::D4, Color::Black), Index::D5.as_bitboard());
+    /// assert_eq!(board.attackers_to(Index::H8, Color::Black), BitBoard::EMPTY);
     /// ```
-    pub fn is_bitmask_under_attack(&self, color: Color, b: BitBoard) -> bool {
-        Generator::G.is_bitmask_under_attack(self, color, b)
+    pub fn attackers_to(&self, i: Index, color: Color) -> BitBoard {
+        Generator::G.attackers_to(self, i, color)
     }
 
-    /// Validate and apply move.
+    /// Returns opponent pieces giving check to the king of the side to move.
     ///
     /// # Examples
     ///
     /// ```
-    /// use chessgen::{ChessBoard, Generator, Move};
-    ///
-    /// let mut board = ChessBoard::from_string("
-    ///       a b c d e f g h
-    ///     8 r n b q k b n r 8
-    ///     7 p p p p p p p p 7
-    ///     6 - - - - - - - - 6
-    ///     5 - - - - - - - - 5
-    ///     4 - - - - - - - - 4
-    ///     3 - - - - - - - - 3
-    ///     2 P P P P P P P P 2
-    ///     1 R N B Q K B N R 1
-    ///       a b c d e f g h
-    /// ").unwrap();
-    ///
-    /// board = board.validate_and_apply_move(&Move::from_string("b1c3").unwrap()).unwrap();
+    /// use chessgen::{BitBoard, ChessBoard, Index};
     ///
-    /// assert_eq!(
-    ///     board.pieces,
-    ///     ChessBoard::from_string("
-    ///       a b c d e f g h
-    ///     8 r n b q k b n r 8
-    ///     7 p p p p p p p p 7
-    ///     6 - - - - - - - - 6
-    ///     5 - - - - - - - - 5
-    ///     4 - - - - - - - - 4
-    ///     3 - - N - - - - - 3
-    ///     2 P P P P P P P P 2
-    ///     1 R - B Q K B N R 1
-    ///       a b c d e f g h
-    ///     ").unwrap().pieces
-    /// );
+    /// assert_eq!(ChessBoard::STANDARD.checkers(), BitBoard::EMPTY);
     ///
-    /// assert!(board.validate_and_apply_move(&Move::from_string("a1a8").unwrap()).is_err());
+    /// // double check by a rook and a knight
+    /// let board = ChessBoard::from_fen("4r1k1/8/8/8/8/5n2/8/4K3 w - - 0 1").unwrap();
+    /// assert_eq!(board.checkers(), Index::E8 | Index::F3);
+    /// assert!(board.is_check());
     /// ```
-    pub fn validate_and_apply_move(&self, m: &Move) -> Result<ChessBoard, IllegalMoveError> {
-        match self.legal_moves().iter().find(|mm| *mm == m) {
-            Some(_) => Ok(self.apply_move(m)),
-            None => Err(IllegalMoveError::IllegalMove(*m)),
+    pub fn checkers(&self) -> BitBoard {
+        match self.my_king() {
+            Some(king) => Generator::G.attackers_to(self, king, self.next_move.opponent()),
+            None => BitBoard::EMPTY,
         }
     }
 
-    /// Apply move to copy of the ChessBoard and return it.
-    /// Move validation is not performed, if the move is not valid,
-    /// results of this operation may be unpredictable.
+    /// Returns pieces of a side pinned to their own king by sliding pieces of the opponent.
     ///
     /// # Examples
     ///
     /// ```
-    /// use chessgen::{ChessBoard, Move};
+    /// use chessgen::{BitBoard, ChessBoard, Color, Index};
     ///
-    /// let m = Move::from_string("e2e4").unwrap();
-    /// let board = ChessBoard::STANDARD.apply_move(&m).pieces;
+    /// let board = ChessBoard::from_fen("4k3/4r3/8/b7/4N3/8/3B4/4K3 w - - 0 1").unwrap();
     ///
-    /// assert_eq!(
-    /// board,
+    /// assert_eq!(board.pinned(Color::White), Index::D2 | Index::E4);
+    /// assert_eq!(board.pinned(Color::Black), BitBoard::EMPTY);
+    /// ```
+    pub fn pinned(&self, color: Color) -> BitBoard {
+        match self.pieces[*color][*Piece::King].bitscan() {
+            Some(king) => Generator::G.pinned(self, king, color.opponent()),
+            None => BitBoard::EMPTY,
+        }
+    }
+
+    /// Returns pieces of any color which are the only blockers
+    /// between the king of a side and sliding pieces of the opponent.
+    /// Own blockers are pinned, opponent blockers discover a check by moving away.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index};
+    ///
+    /// let board = ChessBoard::from_fen("4k3/4r3/8/b7/4n3/8/3B4/4K3 w - - 0 1").unwrap();
+    ///
+    /// assert_eq!(board.blockers_for_king(Color::White), Index::D2 | Index::E4);
+    /// assert_eq!(board.pinned(Color::White), Index::D2.as_bitboard());
+    /// ```
+    pub fn blockers_for_king(&self, color: Color) -> BitBoard {
+        match self.pieces[*color][*Piece::King].bitscan() {
+            Some(king) => Generator::G.blockers(self, king, color.opponent()),
+            None => BitBoard::EMPTY,
+        }
+    }
+
+    /// Returns outcome of the game if it is over, or None if the game goes on.
+    ///
+    /// Checkmate and stalemate take precedence over the draw rules.
+    /// [Outcome::FiftyMoveRule] is reported as soon as a draw may be claimed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Outcome};
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.outcome(), None);
+    ///
+    /// // fool's mate
+    /// let board = ChessBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").unwrap();
+    /// assert_eq!(board.outcome(), Some(Outcome::Checkmate(Color::Black)));
+    ///
+    /// let board = ChessBoard::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    /// assert_eq!(board.outcome(), Some(Outcome::Stalemate));
+    ///
+    /// let board = ChessBoard::from_fen("7k/8/6K1/8/8/8/8/2B5 b - - 0 1").unwrap();
+    /// assert_eq!(board.outcome(), Some(Outcome::InsufficientMaterial));
+    ///
+    /// let board = ChessBoard::from_fen("7k/8/6K1/8/8/8/8/R7 b - - 100 80").unwrap();
+    /// assert_eq!(board.outcome(), Some(Outcome::FiftyMoveRule));
+    ///
+    /// let board = ChessBoard::from_fen("7k/8/6K1/8/8/8/8/R7 b - - 150 100").unwrap();
+    /// assert_eq!(board.outcome(), Some(Outcome::SeventyFiveMoveRule));
+    /// ```
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.legal_moves().is_empty() {
+            if self.is_my_king_under_check() {
+                return Some(Outcome::Checkmate(self.next_move.opponent()));
+            }
+            return Some(Outcome::Stalemate);
+        }
+
+        if self.is_insufficient_material() {
+            return Some(Outcome::InsufficientMaterial);
+        }
+
+        if self.half_move_clock >= 150 {
+            return Some(Outcome::SeventyFiveMoveRule);
+        }
+
+        if self.half_move_clock >= 100 {
+            return Some(Outcome::FiftyMoveRule);
+        }
+
+        None
+    }
+
+    /// Checks if side to move is checkmated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+    /// assert!(!board.is_checkmate());
+    ///
+    /// let board = ChessBoard::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - - 1 1").unwrap();
+    /// assert!(board.is_checkmate());
+    /// ```
+    pub fn is_checkmate(&self) -> bool {
+        self.is_my_king_under_check() && self.legal_moves().is_empty()
+    }
+
+    /// Checks if side to move is stalemated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    /// assert!(board.is_stalemate());
+    /// assert!(!ChessBoard::STANDARD.is_stalemate());
+    /// ```
+    pub fn is_stalemate(&self) -> bool {
+        !self.is_my_king_under_check() && self.legal_moves().is_empty()
+    }
+
+    /// Checks if there is not enough material left on the board to checkmate.
+    /// That is the case for K vs K, KB vs K, KN vs K and any number of bishops
+    /// all standing on squares of the same color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert!(ChessBoard::from_fen("8/8/4k3/8/8/3K4/8/8 w - - 0 1").unwrap().is_insufficient_material());
+    /// assert!(ChessBoard::from_fen("8/8/4k3/8/8/3K4/8/6N1 w - - 0 1").unwrap().is_insufficient_material());
+    /// assert!(ChessBoard::from_fen("8/8/4k3/8/8/3K4/8/5B2 w - - 0 1").unwrap().is_insufficient_material());
+    /// assert!(ChessBoard::from_fen("8/8/4k3/1b6/8/3K4/8/5B2 w - - 0 1").unwrap().is_insufficient_material());
+    ///
+    /// // bishops on different colors
+    /// assert!(!ChessBoard::from_fen("8/8/4k3/2b5/8/3K4/8/5B2 w - - 0 1").unwrap().is_insufficient_material());
+    /// // two knights
+    /// assert!(!ChessBoard::from_fen("8/8/4k3/8/8/3K4/8/5NN1 w - - 0 1").unwrap().is_insufficient_material());
+    /// // pawn
+    /// assert!(!ChessBoard::from_fen("8/8/4k3/8/8/3K4/6P1/8 w - - 0 1").unwrap().is_insufficient_material());
+    /// ```
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut heavy_pieces = BitBoard::EMPTY;
+        let mut bishops = BitBoard::EMPTY;
+        let mut knights = BitBoard::EMPTY;
+
+        for c in Color::VALUES {
+            heavy_pieces |= self.pieces[*c][*Piece::Pawn]
+                | self.pieces[*c][*Piece::Rook]
+                | self.pieces[*c][*Piece::Queen];
+            bishops |= self.pieces[*c][*Piece::Bishop];
+            knights |= self.pieces[*c][*Piece::Knight];
+        }
+
+        if heavy_pieces != BitBoard::EMPTY {
+            return false;
+        }
+
+        let minor_pieces = bishops | knights;
+        if minor_pieces.popcnt() <= 1 {
+            return true;
+        }
+
+        knights == BitBoard::EMPTY
+            && (bishops & BitBoard::LIGHT_SQUARES == BitBoard::EMPTY
+                || bishops & BitBoard::DARK_SQUARES == BitBoard::EMPTY)
+    }
+
+    /// Checks if king of the side to move is under check.
+    pub(crate) fn is_my_king_under_check(&self) -> bool {
+        self.is_bitmask_under_attack(
+            self.next_move.opponent(),
+            self.pieces[*self.next_move][*Piece::King],
+        )
+    }
+
+    /// Checks if BitMask is under attack by a side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, ChessBoard, Color, Index};
+    ///
+    /// let mut board = ChessBoard::from_string("
+    ///      - - - - - - - -
+    ///      - - - - - - - -
+    ///      - - - - - - - -
+    ///      - - - q k - - -
+    ///      - - - - - - - -
+    ///      - - - - - - - -
+    ///      - - - - - - - -
+    ///      - - - Q K - - -
+    /// ").unwrap();
+    ///
+    ///
+    /// assert!(!board.is_bitmask_under_attack(Color::White, Index::A8 | Index::B8));
+    /// assert!(board.is_bitmask_under_attack(Color::White, Index::A4 | Index::A5));
+    /// ```
+    pub fn is_bitmask_under_attack(&self, color: Color, b: BitBoard) -> bool {
+        Generator::G.is_bitmask_under_attack(self, color, b)
+    }
+
+    /// Validate and apply move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Generator, Move};
+    ///
+    /// let mut board = ChessBoard::from_string("
+    ///       a b c d e f g h
+    ///     8 r n b q k b n r 8
+    ///     7 p p p p p p p p 7
+    ///     6 - - - - - - - - 6
+    ///     5 - - - - - - - - 5
+    ///     4 - - - - - - - - 4
+    ///     3 - - - - - - - - 3
+    ///     2 P P P P P P P P 2
+    ///     1 R N B Q K B N R 1
+    ///       a b c d e f g h
+    /// ").unwrap();
+    ///
+    /// board = board.validate_and_apply_move(&Move::from_string("b1c3").unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     board.piece_bitboards(),
+    ///     ChessBoard::from_string("
+    ///       a b c d e f g h
+    ///     8 r n b q k b n r 8
+    ///     7 p p p p p p p p 7
+    ///     6 - - - - - - - - 6
+    ///     5 - - - - - - - - 5
+    ///     4 - - - - - - - - 4
+    ///     3 - - N - - - - - 3
+    ///     2 P P P P P P P P 2
+    ///     1 R - B Q K B N R 1
+    ///       a b c d e f g h
+    ///     ").unwrap().piece_bitboards()
+    /// );
+    ///
+    /// assert!(board.validate_and_apply_move(&Move::from_string("a1a8").unwrap()).is_err());
+    /// ```
+    pub fn validate_and_apply_move(&self, m: &Move) -> Result<ChessBoard, IllegalMoveError> {
+        if m.is_null() {
+            return self.apply_null_move();
+        }
+
+        match self.resolve_move(m) {
+            Some(m) => Ok(self.apply_move(&m)),
+            None => Err(IllegalMoveError::IllegalMove(*m)),
+        }
+    }
+
+    /// Returns the legal move equal to the given move,
+    /// with details of the moving piece, captured piece and kind of the move.
+    /// Returns None if the move is not legal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, MoveKind, Piece};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    ///
+    /// let m = board.resolve_move(&Move::from_string("f3h3").unwrap()).unwrap();
+    /// assert_eq!(m.piece, Some(Piece::Queen));
+    /// assert_eq!(m.captured, Some(Piece::Pawn));
+    /// assert_eq!(m.kind, MoveKind::Normal);
+    ///
+    /// let m = board.resolve_move(&Move::from_string("a2a4").unwrap()).unwrap();
+    /// assert_eq!(m.piece, Some(Piece::Pawn));
+    /// assert!(m.is_double_pawn_push());
+    ///
+    /// assert!(board.resolve_move(&Move::from_string("e1e2").unwrap()).is_none());
+    /// assert!(board.resolve_move(&Move::from_string("a6b5").unwrap()).is_none());
+    /// assert!(board.resolve_move(&Move::from_string("d5d6").unwrap()).is_some());
+    /// assert!(board.resolve_move(&Move::from_string("d5e6").unwrap()).is_some());
+    /// assert!(board.resolve_move(&Move::from_string("d5d4").unwrap()).is_none());
+    /// ```
+    pub fn resolve_move(&self, m: &Move) -> Option<Move> {
+        Generator::G.legal_move(self, m)
+    }
+
+    /// Apply move to copy of the ChessBoard and return it.
+    /// Move validation is not performed, if the move is not valid,
+    /// results of this operation may be unpredictable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let m = Move::from_string("e2e4").unwrap();
+    /// let board = ChessBoard::STANDARD.apply_move(&m);
+    ///
+    /// assert_eq!(
+    /// board.piece_bitboards(),
     /// ChessBoard::from_string("
     ///     r n b q k b n r
     ///     p p p p p p p p
@@ -695,112 +1395,200 @@ impl ChessBoard {
     ///     - - - - - - - -
     ///     P P P P - P P P
     ///     R N B Q K B N R
-    /// ").unwrap().pieces);
+    /// ").unwrap().piece_bitboards());
+    ///
+    /// assert_eq!(
+    ///     ChessBoard::STANDARD.apply_move(&m).to_fen(),
+    ///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    /// );
+    ///
+    /// // null move passes the turn
+    /// assert_eq!(
+    ///     ChessBoard::STANDARD.apply_move(&Move::NULL).to_fen(),
+    ///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 1 1"
+    /// );
+    /// assert_eq!(
+    ///     ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap().apply_move(&Move::NULL).to_fen(),
+    ///     "4k3/8/8/8/8/8/8/4K3 b - - 1 1"
+    /// );
     /// ```
     #[must_use]
     pub fn apply_move(&self, m: &Move) -> Self {
+        let mut board = *self;
+        board.make_move(m);
+        board.debug_check_consistency();
+        board
+    }
+
+    /// Pass the turn to the opponent.
+    /// Switches the side to move, clears en-passant target and updates the clocks and the hash.
+    ///
+    /// Null move is not allowed if the side to move is under check,
+    /// the opponent could capture the king then.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Move, Zobrist};
+    ///
+    /// let board = ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
+    /// let board = board.apply_null_move().unwrap();
+    ///
+    /// assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 1 2");
+    /// assert_eq!(board.hash(), Zobrist::new().hash(&board));
+    /// assert_eq!(board, ChessBoard::STANDARD.validate_and_apply_move(&Move::from_string("e2e4").unwrap())
+    ///     .unwrap().validate_and_apply_move(&Move::NULL).unwrap());
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K2r w - - 0 1").unwrap();
+    /// assert!(board.apply_null_move().is_err());
+    /// ```
+    pub fn apply_null_move(&self) -> Result<ChessBoard, IllegalMoveError> {
+        if self.is_my_king_under_check() {
+            return Err(IllegalMoveError::IllegalMove(Move::NULL));
+        }
+
+        let mut board = *self;
+        board.make_null_move();
+
+        Ok(board)
+    }
+
+    /// Passes the turn in place: switches the side to move, clears en-passant target
+    /// and updates the clocks and the hash.
+    fn make_null_move(&mut self) {
+        let z = &Zobrist::Z;
+
+        self.hash ^= z.side();
+        if let Some(i) = self.en_passant_target.take() {
+            self.hash ^= z.en_passant(i);
+        }
+
+        self.half_move_clock += 1;
+        self.next_move = self.next_move.opponent();
+
+        // full move number is incremented after Black's move
+        if self.next_move == Color::White {
+            self.full_move_number += 1;
+        }
+    }
+
+    /// Apply move to the ChessBoard in place.
+    /// Returns [UndoInfo] which restores the board by [ChessBoard::unmake_move()].
+    /// Move validation is not performed, if the move is not valid,
+    /// results of this operation may be unpredictable.
+    /// [Move::NULL] passes the turn as [ChessBoard::apply_null_move()], without the check test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, Piece};
+    ///
+    /// let mut board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    /// let original = board;
+    ///
+    /// let m = Move::from_string("e5f7").unwrap();
+    /// let undo = board.make_move(&m);
+    /// assert_eq!(undo.captured(), Some(Piece::Pawn));
+    /// assert_eq!(board, original.apply_move(&m));
+    ///
+    /// board.unmake_move(&m, undo);
+    /// assert_eq!(board, original);
+    ///
+    /// let undo = board.make_move(&Move::NULL);
+    /// assert_eq!(board, original.apply_null_move().unwrap());
+    /// assert_eq!(board.hash(), original.apply_null_move().unwrap().hash());
+    ///
+    /// board.unmake_move(&Move::NULL, undo);
+    /// assert_eq!(board, original);
+    /// assert_eq!(board.hash(), original.hash());
+    /// ```
+    pub fn make_move(&mut self, m: &Move) -> UndoInfo {
+        let m = if m.piece.is_some() || m.is_null() {
+            *m
+        } else {
+            self.move_details(m)
+        };
+
         let color = self.next_move;
         let opponent = color.opponent();
 
-        // Find piece type from bitboards (ordered by move frequency)
-        let my = &self.pieces[*color];
-        let piece = if my[*Piece::Pawn].has_bit(m.from) {
-            Piece::Pawn
-        } else if my[*Piece::Knight].has_bit(m.from) {
-            Piece::Knight
-        } else if my[*Piece::Bishop].has_bit(m.from) {
-            Piece::Bishop
-        } else if my[*Piece::Rook].has_bit(m.from) {
-            Piece::Rook
-        } else if my[*Piece::Queen].has_bit(m.from) {
-            Piece::Queen
-        } else {
-            Piece::King
+        let piece = m.piece.unwrap_or(Piece::King);
+        let captured = m.captured;
+        let is_enpassant = m.is_en_passant();
+        let is_capture = captured.is_some() && !is_enpassant;
+
+        let undo = UndoInfo {
+            captured,
+            castling_options: self.castling_options,
+            en_passant_target: self.en_passant_target,
+            half_move_clock: self.half_move_clock,
+            full_move_number: self.full_move_number,
+            hash: self.hash,
+            castling: m.is_castling(),
         };
 
-        let mut pieces = self.pieces;
-        let mut color_pieces = self.color_pieces;
-        let mut castling_options = self.castling_options;
-        let mut en_passant_target = self.en_passant_target;
-        let mut full_move_number = self.full_move_number;
-        let mut half_move_clock = self.half_move_clock + 1;
+        if m.is_null() {
+            self.make_null_move();
+            return undo;
+        }
+
+        let z = &Zobrist::Z;
+        self.hash ^= z.side();
+        self.half_move_clock += 1;
 
         let from_to = m.from | m.to;
-        let is_capture = color_pieces[*opponent].has_bit(m.to);
-        let is_enpassant = piece == Piece::Pawn && en_passant_target.is_some_and(|i| m.to == i);
 
         // reset enPassant
-        en_passant_target = None;
+        if let Some(i) = self.en_passant_target.take() {
+            self.hash ^= z.en_passant(i);
+        }
 
         // make the move
-        pieces[*color][*piece] ^= from_to;
-        color_pieces[*color] ^= from_to;
+        if m.is_castling() {
+            let (king_to, rook_from, rook_to) = self.castling_move(color, &m);
+            let king_move = m.from.as_bitboard() ^ king_to.as_bitboard();
+            let rook_move = rook_from.as_bitboard() ^ rook_to.as_bitboard();
+
+            self.pieces[*color][*Piece::King] ^= king_move;
+            self.pieces[*color][*Piece::Rook] ^= rook_move;
+            self.color_pieces[*color] ^= king_move ^ rook_move;
+            self.hash ^= z.piece(color, Piece::King, m.from)
+                ^ z.piece(color, Piece::King, king_to)
+                ^ z.piece(color, Piece::Rook, rook_from)
+                ^ z.piece(color, Piece::Rook, rook_to);
+        } else {
+            self.pieces[*color][*piece] ^= from_to;
+            self.color_pieces[*color] ^= from_to;
+            self.hash ^= z.piece(color, piece, m.from) ^ z.piece(color, piece, m.to);
+        }
 
         match piece {
-            Piece::Rook => match color {
-                Color::White => match m.from {
-                    Index::A1 => castling_options[*color][*Piece::Queen] = false,
-                    Index::H1 => castling_options[*color][*Piece::King] = false,
-                    _ => {}
-                },
-                Color::Black => match m.from {
-                    Index::A8 => castling_options[*color][*Piece::Queen] = false,
-                    Index::H8 => castling_options[*color][*Piece::King] = false,
-                    _ => {}
-                },
-            },
-            Piece::King => {
-                castling_options[*color][*Piece::Queen] = false;
-                castling_options[*color][*Piece::King] = false;
-                match color {
-                    Color::White => {
-                        if m.from == Index::E1 {
-                            match m.to {
-                                Index::C1 => {
-                                    let rook_move = Index::A1 | Index::D1;
-                                    pieces[*color][*Piece::Rook] ^= rook_move;
-                                    color_pieces[*color] ^= rook_move;
-                                }
-                                Index::G1 => {
-                                    let rook_move = Index::H1 | Index::F1;
-                                    pieces[*color][*Piece::Rook] ^= rook_move;
-                                    color_pieces[*color] ^= rook_move;
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                    Color::Black => {
-                        if m.from == Index::E8 {
-                            match m.to {
-                                Index::C8 => {
-                                    let rook_move = Index::A8 | Index::D8;
-                                    pieces[*color][*Piece::Rook] ^= rook_move;
-                                    color_pieces[*color] ^= rook_move;
-                                }
-                                Index::G8 => {
-                                    let rook_move = Index::H8 | Index::F8;
-                                    pieces[*color][*Piece::Rook] ^= rook_move;
-                                    color_pieces[*color] ^= rook_move;
-                                }
-                                _ => {}
-                            }
-                        }
+            Piece::Rook => {
+                for side in [Piece::King, Piece::Queen] {
+                    if m.from == self.castling_rooks[*color][*side] {
+                        self.castling_options[*color][*side] = false;
                     }
                 }
             }
+            Piece::King => {
+                self.castling_options[*color][*Piece::Queen] = false;
+                self.castling_options[*color][*Piece::King] = false;
+            }
             Piece::Pawn => {
-                half_move_clock = 0;
-                if m.to.distance_to(m.from) > 10 {
+                self.half_move_clock = 0;
+                if m.is_double_pawn_push() {
                     let i = if color == Color::White {
                         m.from.shifted_north()
                     } else {
                         m.from.shifted_south()
                     };
-                    en_passant_target = Some(i.unwrap());
+                    self.en_passant_target = Some(i.unwrap());
+                    self.hash ^= z.en_passant(i.unwrap());
                 } else if let Some(promotion) = m.promotion {
-                    pieces[*color][*Piece::Pawn] ^= m.to;
-                    pieces[*color][*promotion] ^= m.to;
+                    self.pieces[*color][*Piece::Pawn] ^= m.to;
+                    self.pieces[*color][*promotion] ^= m.to;
+                    self.hash ^=
+                        z.piece(color, Piece::Pawn, m.to) ^ z.piece(color, promotion, m.to);
                     // No change to color_pieces - piece stays at same square
                 }
             }
@@ -808,64 +1596,384 @@ impl ChessBoard {
         }
 
         if is_enpassant {
-            half_move_clock = 0;
+            let i = ChessBoard::en_passant_capture(color, m.to);
+            self.pieces[*opponent][*Piece::Pawn] ^= i;
+            self.color_pieces[*opponent] ^= i;
+            self.hash ^= z.piece(opponent, Piece::Pawn, i);
+        }
 
-            match color {
-                Color::White => {
-                    let i = m.to.shifted_south().unwrap();
-                    pieces[*Color::Black][*Piece::Pawn] ^= i;
-                    color_pieces[*Color::Black] ^= i;
-                }
-                Color::Black => {
-                    let i = m.to.shifted_north().unwrap();
-                    pieces[*Color::White][*Piece::Pawn] ^= i;
-                    color_pieces[*Color::White] ^= i;
+        if is_capture && let Some(p) = captured {
+            self.half_move_clock = 0;
+
+            self.pieces[*opponent][*p] ^= m.to;
+            self.color_pieces[*opponent] ^= m.to;
+            self.hash ^= z.piece(opponent, p, m.to);
+
+            for side in [Piece::King, Piece::Queen] {
+                if m.to == self.castling_rooks[*opponent][*side] {
+                    self.castling_options[*opponent][*side] = false;
                 }
             }
         }
 
-        if is_capture {
-            half_move_clock = 0;
-
-            for p in Piece::VALUES {
-                if pieces[*opponent][*p].has_bit(m.to) {
-                    pieces[*opponent][*p] ^= m.to;
-                    break;
+        // castling options changes
+        for c in Color::VALUES {
+            for p in [Piece::King, Piece::Queen] {
+                if self.castling_options[*c][*p] != undo.castling_options[*c][*p] {
+                    self.hash ^= z.castling(c, p, self.castling_rooks[*c][*p]);
                 }
             }
-            color_pieces[*opponent] ^= m.to;
-
-            match color {
-                Color::White => match m.to {
-                    Index::A8 => castling_options[*Color::Black][*Piece::Queen] = false,
-                    Index::H8 => castling_options[*Color::Black][*Piece::King] = false,
-                    _ => {}
-                },
-                Color::Black => match m.to {
-                    Index::A1 => castling_options[*Color::White][*Piece::Queen] = false,
-                    Index::H1 => castling_options[*Color::White][*Piece::King] = false,
-                    _ => {}
-                },
-            }
         }
 
-        let next_move = opponent;
+        self.next_move = opponent;
 
-        if next_move == Color::Black {
-            full_move_number += 1;
+        // full move number is incremented after Black's move
+        if self.next_move == Color::White {
+            self.full_move_number += 1;
         }
 
+        undo
+    }
+
+    /// Take back the move made by [ChessBoard::make_move()].
+    /// The move and [UndoInfo] must be those of the last move made,
+    /// otherwise results of this operation may be unpredictable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// // make and unmake all moves and compare the board with the original
+    /// fn check(board: &mut ChessBoard, depth: usize) {
+    ///     let original = *board;
+    ///     for m in board.legal_moves() {
+    ///         let undo = board.make_move(&m);
+    ///         assert_eq!(*board, original.apply_move(&m), "{} {}", original.to_fen(), m);
+    ///         if depth > 1 {
+    ///             check(board, depth - 1);
+    ///         }
+    ///         board.unmake_move(&m, undo);
+    ///         assert_eq!(*board, original, "{} {}", original.to_fen(), m);
+    ///     }
+    /// }
+    ///
+    /// for fen in [
+    ///     ChessBoard::STANDARD_BOARD_FEN,
+    ///     "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+    ///     "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+    ///     "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    ///     "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    /// ] {
+    ///     check(&mut ChessBoard::from_fen(fen).unwrap(), 3);
+    /// }
+    /// ```
+    pub fn unmake_move(&mut self, m: &Move, undo: UndoInfo) {
+        let opponent = self.next_move;
+        let color = opponent.opponent();
+
+        if undo.castling {
+            let (king_to, rook_from, rook_to) = self.castling_move(color, m);
+            let king_move = m.from.as_bitboard() ^ king_to.as_bitboard();
+            let rook_move = rook_from.as_bitboard() ^ rook_to.as_bitboard();
+
+            self.pieces[*color][*Piece::King] ^= king_move;
+            self.pieces[*color][*Piece::Rook] ^= rook_move;
+            self.color_pieces[*color] ^= king_move ^ rook_move;
+        } else if !m.is_null() {
+            self.unmake_piece_move(color, m, &undo);
+        }
+
+        self.next_move = color;
+        self.castling_options = undo.castling_options;
+        self.en_passant_target = undo.en_passant_target;
+        self.half_move_clock = undo.half_move_clock;
+        self.full_move_number = undo.full_move_number;
+        self.hash = undo.hash;
+    }
+
+    /// Takes back pieces of a move which is not castling.
+    #[inline(always)]
+    fn unmake_piece_move(&mut self, color: Color, m: &Move, undo: &UndoInfo) {
+        let opponent = color.opponent();
+
+        let mut piece = self.colored_piece_at(color, m.to);
+        if let Some(promotion) = m.promotion
+            && piece == promotion
+        {
+            self.pieces[*color][*promotion] ^= m.to;
+            self.pieces[*color][*Piece::Pawn] ^= m.to;
+            piece = Piece::Pawn;
+        }
+
+        let from_to = m.from | m.to;
+        self.pieces[*color][*piece] ^= from_to;
+        self.color_pieces[*color] ^= from_to;
+
+        if let Some(captured) = undo.captured {
+            let i = if piece == Piece::Pawn && undo.en_passant_target == Some(m.to) {
+                ChessBoard::en_passant_capture(color, m.to)
+            } else {
+                m.to
+            };
+            self.pieces[*opponent][*captured] ^= i;
+            self.color_pieces[*opponent] ^= i;
+        }
+    }
+
+    /// Returns the move with details of the moving piece, captured piece and kind of the move.
+    /// Legality of the move is not checked.
+    fn move_details(&self, m: &Move) -> Move {
+        let piece = self.colored_piece_at(self.next_move, m.from);
+
+        let kind = match piece {
+            Piece::Pawn if self.en_passant_target == Some(m.to) => MoveKind::EnPassant,
+            Piece::Pawn if m.from.distance_to(m.to) == 16 => MoveKind::DoublePawnPush,
+            Piece::King if self.is_castling(m) => MoveKind::Castling,
+            _ => MoveKind::Normal,
+        };
+
+        let captured = if kind == MoveKind::EnPassant {
+            Some(Piece::Pawn)
+        } else {
+            self.opponent_piece_at(m.to)
+        };
+
+        Move {
+            piece: Some(piece),
+            captured,
+            kind,
+            ..*m
+        }
+    }
+
+    /// Returns piece of the opponent of the side to move at the index.
+    #[inline(always)]
+    pub(crate) fn opponent_piece_at(&self, i: Index) -> Option<Piece> {
+        let opponent = self.next_move.opponent();
+        if self.color_pieces[*opponent].has_bit(i) {
+            Some(self.colored_piece_at(opponent, i))
+        } else {
+            None
+        }
+    }
+
+    /// Returns type of the piece of given color at the index,
+    /// King if there is no other piece (ordered by move frequency).
+    #[inline(always)]
+    fn colored_piece_at(&self, color: Color, i: Index) -> Piece {
+        let pieces = &self.pieces[*color];
+        if pieces[*Piece::Pawn].has_bit(i) {
+            Piece::Pawn
+        } else if pieces[*Piece::Knight].has_bit(i) {
+            Piece::Knight
+        } else if pieces[*Piece::Bishop].has_bit(i) {
+            Piece::Bishop
+        } else if pieces[*Piece::Rook].has_bit(i) {
+            Piece::Rook
+        } else if pieces[*Piece::Queen].has_bit(i) {
+            Piece::Queen
+        } else {
+            Piece::King
+        }
+    }
+
+    /// Checks if a king move of the side to move is castling.
+    /// Castling is a king move by two fields, or king captures own rook in Chess960 mode.
+    #[inline(always)]
+    pub(crate) fn is_castling(&self, m: &Move) -> bool {
+        if self.chess960 {
+            self.pieces[*self.next_move][*Piece::Rook].has_bit(m.to)
+        } else {
+            m.from.distance_to(m.to) == 2
+        }
+    }
+
+    /// Returns side of a castling move, [Piece::King] or [Piece::Queen].
+    #[inline(always)]
+    pub(crate) fn castling_side(m: &Move) -> Piece {
+        if m.to.file() > m.from.file() {
+            Piece::King
+        } else {
+            Piece::Queen
+        }
+    }
+
+    /// Returns fields to which the king and the rook move when castling: (king, rook).
+    /// Castling ends on the same fields as in standard chess also in Chess960.
+    #[inline(always)]
+    pub(crate) const fn castling_targets(color: Color, side: Piece) -> (Index, Index) {
+        let rank = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        match side {
+            Piece::King => (
+                Index::from_rank_and_file(rank, 6),
+                Index::from_rank_and_file(rank, 5),
+            ),
+            _ => (
+                Index::from_rank_and_file(rank, 2),
+                Index::from_rank_and_file(rank, 3),
+            ),
+        }
+    }
+
+    /// Returns (king to, rook from, rook to) fields of a castling move.
+    #[inline(always)]
+    fn castling_move(&self, color: Color, m: &Move) -> (Index, Index, Index) {
+        let side = ChessBoard::castling_side(m);
+        let (king_to, rook_to) = ChessBoard::castling_targets(color, side);
+        (king_to, self.castling_rooks[*color][*side], rook_to)
+    }
+
+    /// Returns the rook castling with the king of a color on a side
+    /// ([Piece::King] or [Piece::Queen]), None if the castling option is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, Piece};
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.castling_rook(Color::White, Piece::King), Some(Index::H1));
+    /// assert_eq!(ChessBoard::STANDARD.castling_rook(Color::Black, Piece::Queen), Some(Index::A8));
+    ///
+    /// let board = ChessBoard::from_fen("1r2k1r1/8/8/8/8/8/8/1R2K1R1 w Gb - 0 1").unwrap();
+    /// assert_eq!(board.castling_rook(Color::White, Piece::King), Some(Index::G1));
+    /// assert_eq!(board.castling_rook(Color::White, Piece::Queen), None);
+    /// assert_eq!(board.castling_rook(Color::Black, Piece::Queen), Some(Index::B8));
+    /// ```
+    #[must_use]
+    pub const fn castling_rook(&self, color: Color, side: Piece) -> Option<Index> {
+        if self.castling_options[color as usize][side as usize] {
+            Some(self.castling_rooks[color as usize][side as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the board is in Chess960 (Fischer Random Chess) mode.
+    /// Castling moves of Chess960 boards are encoded as king captures own rook,
+    /// see [ChessBoard::to_chess960()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// assert!(!ChessBoard::STANDARD.is_chess960());
+    /// assert!(ChessBoard::chess960(0).unwrap().is_chess960());
+    /// ```
+    #[must_use]
+    pub fn is_chess960(&self) -> bool {
+        self.chess960
+    }
+
+    /// Returns copy of the board in Chess960 mode.
+    /// Positions of standard chess are valid Chess960 positions, castling moves
+    /// change from king moving by two fields to king capturing own rook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    /// assert!(board.resolve_move(&Move::from_string("e1g1").unwrap()).unwrap().is_castling());
+    ///
+    /// let board = board.to_chess960();
+    /// assert!(board.resolve_move(&Move::from_string("e1g1").unwrap()).is_none());
+    /// assert!(board.resolve_move(&Move::from_string("e1h1").unwrap()).unwrap().is_castling());
+    /// assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+    /// ```
+    #[must_use]
+    pub fn to_chess960(&self) -> Self {
         ChessBoard {
-            pieces,
-            next_move,
-            castling_options,
-            en_passant_target,
-            half_move_clock,
-            full_move_number,
-            color_pieces,
+            chess960: true,
+            ..*self
+        }
+    }
+
+    /// Checks in debug builds that the cached pieces and the hash match the pieces
+    /// and that no field is occupied by more than one piece.
+    #[inline(always)]
+    fn debug_check_consistency(&self) {
+        if cfg!(debug_assertions) {
+            let count: usize = self.pieces.iter().flatten().map(|b| b.popcnt()).sum();
+            debug_assert_eq!(count, self.all_pieces().popcnt(), "overlapping pieces");
+            debug_assert_eq!(
+                self.color_pieces,
+                ChessBoard::compute_color_pieces(&self.pieces),
+                "cached pieces out of sync"
+            );
+            debug_assert_eq!(self.hash, Zobrist::Z.hash(self), "hash out of sync");
         }
     }
 
+    /// Returns index of the pawn captured en-passant by color moving to the target.
+    #[inline(always)]
+    fn en_passant_capture(color: Color, target: Index) -> Index {
+        match color {
+            Color::White => target.shifted_south().unwrap(),
+            Color::Black => target.shifted_north().unwrap(),
+        }
+    }
+
+    /// Returns Zobrist hash of the board.
+    ///
+    /// The hash is computed by constructors and updated incrementally by [ChessBoard::apply_move()]
+    /// and by the mutators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, PerfT, Zobrist};
+    ///
+    /// let zobrist = Zobrist::new();
+    ///
+    /// // compare incremental hash with full computation
+    /// fn check(zobrist: &Zobrist, board: &ChessBoard, depth: usize) {
+    ///     assert_eq!(board.hash(), zobrist.hash(board), "{}", board.to_fen());
+    ///     if depth > 0 {
+    ///         for m in board.legal_moves() {
+    ///             check(zobrist, &board.apply_move(&m), depth - 1);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// for fen in [
+    ///     ChessBoard::STANDARD_BOARD_FEN,
+    ///     "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+    ///     "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+    ///     "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    /// ] {
+    ///     check(&zobrist, &ChessBoard::from_fen(fen).unwrap(), 3);
+    /// }
+    ///
+    /// // transpositions lead to the same hash
+    /// let board = ChessBoard::STANDARD;
+    /// let m = |s| Move::from_string(s).unwrap();
+    /// assert_eq!(
+    ///     board.apply_move(&m("g1f3")).apply_move(&m("g8f6")).apply_move(&m("b1c3")).hash(),
+    ///     board.apply_move(&m("b1c3")).apply_move(&m("g8f6")).apply_move(&m("g1f3")).hash(),
+    /// );
+    /// assert_ne!(board.hash(), board.apply_move(&m("g1f3")).hash());
+    ///
+    /// // Chess960 positions differing only in the castling rook have different hashes
+    /// let a = ChessBoard::from_fen("4k3/8/8/8/8/8/8/RR2K3 w A - 0 1").unwrap();
+    /// let b = ChessBoard::from_fen("4k3/8/8/8/8/8/8/RR2K3 w B - 0 1").unwrap();
+    /// assert_ne!(a.hash(), b.hash());
+    ///
+    /// let perft = PerfT::new(1024);
+    /// assert_eq!(perft.perft_n(&a, 1), 21);
+    /// assert_eq!(perft.perft_n(&b, 1), 22);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
     /// Creates new board form a string.
     /// String may or may not be decorated with coordinates.
     ///
@@ -929,6 +2037,45 @@ impl ChessBoard {
     /// ```
     #[must_use]
     pub fn to_fen(&self) -> String {
+        self.fen(false)
+    }
+
+    /// Returns Shredder-FEN representation of this board,
+    /// castling options are denoted by files of the castling rooks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// assert_eq!(
+    ///     ChessBoard::STANDARD.to_shredder_fen(),
+    ///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1"
+    /// );
+    ///
+    /// let fen = "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+    /// let board = ChessBoard::from_fen(fen).unwrap();
+    /// assert!(board.is_chess960());
+    /// assert_eq!(board.to_shredder_fen(), fen);
+    /// assert_eq!(board.to_fen(), "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9");
+    /// assert_eq!(ChessBoard::from_fen(&board.to_fen()).unwrap(), board);
+    ///
+    /// // lost castling option does not make equal positions different
+    /// let board = ChessBoard::from_fen("bbqnnrkr/8/8/8/8/8/8/BBQNNRKR w HFhf - 0 1")
+    ///     .unwrap()
+    ///     .apply_move(&Move::from_string("f1f2").unwrap());
+    /// assert_eq!(board.to_shredder_fen(), "bbqnnrkr/8/8/8/8/8/5R2/BBQNN1KR b Hhf - 1 1");
+    /// assert_eq!(ChessBoard::from_fen(&board.to_shredder_fen()).unwrap(), board);
+    /// assert_eq!(ChessBoard::from_packed(&board.to_packed().unwrap()).unwrap(), board);
+    /// assert_eq!(board.flipped_colors().flipped_colors(), board);
+    /// ```
+    #[must_use]
+    pub fn to_shredder_fen(&self) -> String {
+        self.fen(true)
+    }
+
+    /// Returns FEN or Shredder-FEN representation of this board.
+    fn fen(&self, shredder: bool) -> String {
         let mut pieces = Vec::new();
 
         for c in Color::VALUES {
@@ -966,21 +2113,7 @@ impl ChessBoard {
         write!(fen, " {} ", self.next_move).unwrap();
 
         // Castling
-        let mut some_castling = false;
-        for c in Color::VALUES {
-            if self.castling_options[*c][*Piece::King] {
-                write!(fen, "{}", Piece::King.to_char(c)).unwrap();
-                some_castling = true;
-            }
-            if self.castling_options[*c][*Piece::Queen] {
-                write!(fen, "{}", Piece::Queen.to_char(c)).unwrap();
-                some_castling = true;
-            }
-        }
-        if !some_castling {
-            write!(fen, "-").unwrap();
-        }
-        write!(fen, " ").unwrap();
+        write!(fen, "{} ", self.castling_to_fen(shredder)).unwrap();
 
         // enPassant
         if let Some(target) = self.en_passant_target {
@@ -996,6 +2129,8 @@ impl ChessBoard {
     }
 
     /// Returns ChessBoard from FEN definition.
+    /// Parsing is lenient, missing fields are defaulted and the position is not validated,
+    /// see [ChessBoard::from_fen_strict()] for strict parsing.
     ///
     /// # Examples
     ///
@@ -1020,7 +2155,7 @@ impl ChessBoard {
 
         let mut pieces = [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()];
         let mut next_move = Color::White;
-        let mut castling_options = [[true; 2]; Color::VALUES.len()];
+        let mut castling = None;
         let mut en_passant_target = None;
         let mut full_move_number = 1;
         let mut half_move_clock = 0;
@@ -1096,15 +2231,7 @@ impl ChessBoard {
 
             match c {
                 b' ' => break,
-                b'-' => {}
-                _ => match Piece::from_char(c as char) {
-                    Ok((color, piece)) => match piece {
-                        Piece::King => castling_options[*color][*Piece::King] = true,
-                        Piece::Queen => castling_options[*color][*Piece::Queen] = true,
-                        _ => return Err(InvalidFENStringError::InvalidString(fen.to_string())),
-                    },
-                    Err(_) => return Err(InvalidFENStringError::InvalidString(fen.to_string())),
-                },
+                _ => castling.get_or_insert_with(String::new).push(c as char),
             }
         }
 
@@ -1162,39 +2289,169 @@ impl ChessBoard {
             full_move_number = n;
         }
 
-        // fix castling - rooks
-        if !pieces[*Color::White][*Piece::Rook].has_bit(Index::A1) {
-            castling_options[*Color::White][*Piece::Queen] = false;
-        }
-        if !pieces[*Color::White][*Piece::Rook].has_bit(Index::H1) {
-            castling_options[*Color::White][*Piece::King] = false;
-        }
-        if !pieces[*Color::Black][*Piece::Rook].has_bit(Index::A8) {
-            castling_options[*Color::Black][*Piece::Queen] = false;
-        }
-        if !pieces[*Color::Black][*Piece::Rook].has_bit(Index::H8) {
-            castling_options[*Color::Black][*Piece::King] = false;
+        // castling options without castling field are set if the pieces are at standard fields
+        let (castling_options, castling_rooks, chess960) =
+            match ChessBoard::castling_from_fen(&pieces, castling.as_deref().unwrap_or("KQkq")) {
+                Some((options, rooks, chess960)) if castling.is_none() && chess960 => {
+                    let mut options = options;
+                    for c in Color::VALUES {
+                        for side in [Piece::King, Piece::Queen] {
+                            options[*c][*side] &= rooks[*c][*side]
+                                == ChessBoard::STANDARD_CASTLING_ROOKS[*c][*side]
+                                && pieces[*c][*Piece::King]
+                                    .has_bit(Index::from_rank_and_file(rooks[*c][*side].rank(), 4));
+                        }
+                    }
+                    (options, ChessBoard::STANDARD_CASTLING_ROOKS, false)
+                }
+                Some(castling) => castling,
+                None => return Err(InvalidFENStringError::InvalidString(fen.to_string())),
+            };
+
+        Ok(ChessBoard::from_parts(
+            pieces,
+            next_move,
+            castling_options,
+            castling_rooks,
+            chess960,
+            en_passant_target,
+            half_move_clock,
+            full_move_number,
+        ))
+    }
+
+    /// Parses castling field of FEN, X-FEN or Shredder-FEN: `KQkq`, file letters or `-`.
+    /// `K` and `Q` stand for the outermost rook on the side of the king,
+    /// file letters for the rook on the file, upper case for White.
+    /// Options without a king and a rook on the back rank are ignored.
+    ///
+    /// Returns castling options, castling rooks and flag if the castling is Chess960 castling,
+    /// None if the field is invalid.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn castling_from_fen(
+        pieces: &[[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()],
+        castling: &str,
+    ) -> Option<(
+        [[bool; 2]; Color::VALUES.len()],
+        [[Index; 2]; Color::VALUES.len()],
+        bool,
+    )> {
+        let mut options = [[false; 2]; Color::VALUES.len()];
+        let mut rooks = ChessBoard::STANDARD_CASTLING_ROOKS;
+        let mut chess960 = false;
+
+        for c in castling.chars() {
+            if c == '-' {
+                continue;
+            }
+
+            let color = if c.is_ascii_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let back_rank = match color {
+                Color::White => BitBoard::RANK_1,
+                Color::Black => BitBoard::RANK_8,
+            };
+
+            let Some(king) = (pieces[*color][*Piece::King] & back_rank).bitscan() else {
+                continue;
+            };
+            let back_rank_rooks = pieces[*color][*Piece::Rook] & back_rank;
+
+            let rook = match c.to_ascii_lowercase() {
+                'k' => {
+                    let b = BitBoard::ray(king, Direction::East) & back_rank_rooks;
+                    // outermost rook is the highest one
+                    (b != BitBoard::EMPTY)
+                        .then(|| Index::new(63 - b.state.leading_zeros() as usize))
+                }
+                'q' => (BitBoard::ray(king, Direction::West) & back_rank_rooks).bitscan(),
+                f @ 'a'..='h' => {
+                    chess960 = true;
+                    let rook = Index::from_rank_and_file(king.rank(), f as usize - 'a' as usize);
+                    back_rank_rooks.has_bit(rook).then_some(rook)
+                }
+                _ => return None,
+            };
+
+            let Some(rook) = rook else {
+                continue;
+            };
+
+            let side = ChessBoard::castling_side(&Move::new(king, rook, None));
+            options[*color][*side] = true;
+            rooks[*color][*side] = rook;
+            chess960 |=
+                king.file() != 4 || rook != ChessBoard::STANDARD_CASTLING_ROOKS[*color][*side];
         }
 
-        // fix castling - kings
-        if !pieces[*Color::White][*Piece::King].has_bit(Index::E1) {
-            castling_options[*Color::White][*Piece::King] = false;
-            castling_options[*Color::White][*Piece::Queen] = false;
+        Some((options, rooks, chess960))
+    }
+
+    /// Returns castling field of FEN: X-FEN if `shredder` is false, Shredder-FEN otherwise.
+    pub(super) fn castling_to_fen(&self, shredder: bool) -> String {
+        let mut fen = String::new();
+
+        for c in Color::VALUES {
+            let rooks = self.pieces[*c][*Piece::Rook];
+            for side in [Piece::King, Piece::Queen] {
+                let Some(rook) = self.castling_rook(c, side) else {
+                    continue;
+                };
+
+                let direction = match side {
+                    Piece::King => Direction::East,
+                    _ => Direction::West,
+                };
+
+                // X-FEN uses file letters only if the castling rook is not the outermost one
+                if shredder || BitBoard::ray(rook, direction) & rooks != BitBoard::EMPTY {
+                    let file = (b'a' + rook.file() as u8) as char;
+                    fen.push(match c {
+                        Color::White => file.to_ascii_uppercase(),
+                        Color::Black => file,
+                    });
+                } else {
+                    fen.push(side.to_char(c));
+                }
+            }
         }
-        if !pieces[*Color::Black][*Piece::King].has_bit(Index::E8) {
-            castling_options[*Color::Black][*Piece::King] = false;
-            castling_options[*Color::Black][*Piece::Queen] = false;
+
+        if fen.is_empty() {
+            fen.push('-');
         }
 
-        Ok(ChessBoard {
+        fen
+    }
+
+    /// Constructs a board from its parts, computes the cached pieces and the hash.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn from_parts(
+        pieces: [[BitBoard; Piece::VALUES.len()]; Color::VALUES.len()],
+        next_move: Color,
+        castling_options: [[bool; 2]; Color::VALUES.len()],
+        castling_rooks: [[Index; 2]; Color::VALUES.len()],
+        chess960: bool,
+        en_passant_target: Option<Index>,
+        half_move_clock: usize,
+        full_move_number: usize,
+    ) -> Self {
+        let mut board = ChessBoard {
             pieces,
             next_move,
             castling_options,
+            castling_rooks,
+            chess960,
             en_passant_target,
             half_move_clock,
             full_move_number,
             color_pieces: ChessBoard::compute_color_pieces(&pieces),
-        })
+            hash: 0,
+        };
+        board.hash = Zobrist::Z.hash(&board);
+        board
     }
 }
 
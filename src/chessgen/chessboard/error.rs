@@ -1,9 +1,22 @@
 use std::fmt;
 
+use crate::Index;
+
+use super::{Color, FenField, Piece};
+
 /// An error returned when parsing a FEN string into ChessBoard.
+///
+/// [crate::ChessBoard::from_fen()] reports only [InvalidFENStringError::InvalidString],
+/// [crate::ChessBoard::from_fen_strict()] reports the precise reason.
 #[derive(Debug)]
 pub enum InvalidFENStringError {
     InvalidString(String),
+    InvalidFieldCount(usize),
+    InvalidRankCount(usize),
+    InvalidRankLength(usize, usize),
+    InvalidRankChar(usize, char),
+    InvalidField(FenField, String),
+    InvalidChessBoard(InvalidChessBoardError),
 }
 
 impl std::error::Error for InvalidFENStringError {}
@@ -12,6 +25,54 @@ impl fmt::Display for InvalidFENStringError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::InvalidString(s) => write!(f, "Invalid FEN string: '{}'", s),
+            Self::InvalidFieldCount(n) => write!(f, "Invalid number of FEN fields: {}", n),
+            Self::InvalidRankCount(n) => write!(f, "Invalid number of FEN ranks: {}", n),
+            Self::InvalidRankLength(rank, n) => {
+                write!(f, "Invalid number of squares on FEN rank {}: {}", rank, n)
+            }
+            Self::InvalidRankChar(rank, c) => {
+                write!(f, "Invalid character on FEN rank {}: '{}'", rank, c)
+            }
+            Self::InvalidField(field, s) => write!(f, "Invalid FEN {}: '{}'", field, s),
+            Self::InvalidChessBoard(e) => write!(f, "Invalid FEN position: {}", e),
+        }
+    }
+}
+
+impl From<InvalidChessBoardError> for InvalidFENStringError {
+    fn from(e: InvalidChessBoardError) -> Self {
+        Self::InvalidChessBoard(e)
+    }
+}
+
+/// An error returned when a ChessBoard is not a valid chess position.
+#[derive(Debug)]
+pub enum InvalidChessBoardError {
+    OverlappingPieces(Index),
+    InvalidKingCount(Color, usize),
+    TooManyPawns(Color, usize),
+    TooManyPieces(Color, usize),
+    PawnOnBackRank(Color, Index),
+    InvalidCastling(Color, Piece),
+    InvalidEnPassant(Index),
+    OpponentInCheck(Color),
+}
+
+impl std::error::Error for InvalidChessBoardError {}
+
+impl fmt::Display for InvalidChessBoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OverlappingPieces(i) => write!(f, "More than one piece on {}", i),
+            Self::InvalidKingCount(c, n) => write!(f, "Invalid number of {:?} kings: {}", c, n),
+            Self::TooManyPawns(c, n) => write!(f, "Too many {:?} pawns: {}", c, n),
+            Self::TooManyPieces(c, n) => write!(f, "Too many {:?} pieces: {}", c, n),
+            Self::PawnOnBackRank(c, i) => write!(f, "{:?} pawn on back rank: {}", c, i),
+            Self::InvalidCastling(c, side) => {
+                write!(f, "{:?} can not castle on {:?} side", c, side)
+            }
+            Self::InvalidEnPassant(i) => write!(f, "Invalid en-passant target: {}", i),
+            Self::OpponentInCheck(c) => write!(f, "{:?} is in check but not to move", c),
         }
     }
 }
@@ -32,6 +93,34 @@ impl fmt::Display for InvalidChessBoardStringError {
     }
 }
 
+/// An error returned when packing or unpacking a ChessBoard.
+#[derive(Debug)]
+pub enum InvalidPackedChessBoardError {
+    InvalidLength(usize),
+    TooManyPieces(usize),
+    InvalidPiece(u8),
+    InvalidEnPassant(u8),
+    ClockOverflow(usize),
+    UnsupportedCastling(String),
+}
+
+impl std::error::Error for InvalidPackedChessBoardError {}
+
+impl fmt::Display for InvalidPackedChessBoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength(n) => write!(f, "Invalid packed chessboard length: {} bytes", n),
+            Self::TooManyPieces(n) => write!(f, "Too many pieces to pack a chessboard: {}", n),
+            Self::InvalidPiece(p) => write!(f, "Invalid packed piece code: {}", p),
+            Self::InvalidEnPassant(i) => write!(f, "Invalid packed en-passant target: {}", i),
+            Self::ClockOverflow(n) => write!(f, "Move clock out of packed range: {}", n),
+            Self::UnsupportedCastling(s) => {
+                write!(f, "Castling with inner rooks can not be packed: '{}'", s)
+            }
+        }
+    }
+}
+
 /// An error returned when parsing a string into Piece.
 #[derive(Debug)]
 pub enum InvalidPieceNotationError {
@@ -68,6 +157,8 @@ impl fmt::Display for InvalidColorNotationError {
 #[derive(Debug)]
 pub enum InvalidMoveNotationError {
     InvalidString(String),
+    IllegalMove(String),
+    AmbiguousMove(String),
 }
 
 impl std::error::Error for InvalidMoveNotationError {}
@@ -76,6 +167,8 @@ impl fmt::Display for InvalidMoveNotationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::InvalidString(s) => write!(f, "Invalid Move notation: '{}'", s),
+            Self::IllegalMove(s) => write!(f, "Illegal Move: '{}'", s),
+            Self::AmbiguousMove(s) => write!(f, "Ambiguous Move notation: '{}'", s),
         }
     }
 }
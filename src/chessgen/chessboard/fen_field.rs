@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// Field of a FEN string, see [crate::InvalidFENStringError::InvalidField].
+///
+/// See: [ChessProgramming Forsyth-Edwards Notation](https://www.chessprogramming.org/Forsyth-Edwards_Notation)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FenField {
+    /// Piece placement, ranks 8 to 1 separated by `/`.
+    Pieces,
+    /// Side to move, `w` or `b`.
+    NextMove,
+    /// Castling options, `KQkq`, file letters or `-`.
+    Castling,
+    /// En-passant target square or `-`.
+    EnPassant,
+    /// Number of half moves since the last capture or pawn move.
+    HalfMoveClock,
+    /// Number of the full move, starting at 1.
+    FullMoveNumber,
+}
+
+impl FenField {
+    /// All fields in order of the FEN string.
+    pub const VALUES: [FenField; 6] = [
+        FenField::Pieces,
+        FenField::NextMove,
+        FenField::Castling,
+        FenField::EnPassant,
+        FenField::HalfMoveClock,
+        FenField::FullMoveNumber,
+    ];
+}
+
+/// Display and to_string() for a FenField.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::FenField;
+///
+/// assert_eq!("en-passant target", FenField::EnPassant.to_string());
+/// ```
+impl fmt::Display for FenField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FenField::Pieces => write!(f, "piece placement"),
+            FenField::NextMove => write!(f, "side to move"),
+            FenField::Castling => write!(f, "castling"),
+            FenField::EnPassant => write!(f, "en-passant target"),
+            FenField::HalfMoveClock => write!(f, "half move clock"),
+            FenField::FullMoveNumber => write!(f, "full move number"),
+        }
+    }
+}
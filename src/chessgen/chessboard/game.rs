@@ -0,0 +1,253 @@
+use crate::{ChessBoard, IllegalMoveError, Move, Outcome, Piece, Zobrist};
+
+/// Chess game - a ChessBoard together with history of played moves.
+///
+/// Keeps Zobrist hashes of all positions played, so repetitions can be detected.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, Game, Move};
+///
+/// let mut game = Game::new(ChessBoard::STANDARD);
+///
+/// for m in ["g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8"] {
+///     game.push(&Move::from_string(m).unwrap()).unwrap();
+/// }
+///
+/// assert_eq!(game.board(), &ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 8 5").unwrap());
+/// assert_eq!(game.repetitions(), 3);
+/// assert!(game.is_threefold_repetition());
+/// assert!(game.can_claim_draw());
+///
+/// assert_eq!(game.pop(), Some(Move::from_string("f6g8").unwrap()));
+/// assert!(!game.is_threefold_repetition());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Game {
+    /// Position the game started from.
+    initial_board: ChessBoard,
+    /// Boards before each of the played moves.
+    boards: Vec<ChessBoard>,
+    /// Current position.
+    board: ChessBoard,
+    /// Played moves.
+    moves: Vec<Move>,
+    /// Hashes of all positions of the game, including the current one.
+    hashes: Vec<u64>,
+}
+
+/// Constructs a new game from the standard layout.
+impl Default for Game {
+    fn default() -> Self {
+        Game::new(ChessBoard::STANDARD)
+    }
+}
+
+impl Game {
+    /// Constructs a new game starting at a given position.
+    pub fn new(board: ChessBoard) -> Self {
+        let hash = Game::repetition_hash(&board);
+
+        Game {
+            initial_board: board,
+            boards: Vec::new(),
+            board,
+            moves: Vec::new(),
+            hashes: vec![hash],
+        }
+    }
+
+    /// Returns the position the game started from.
+    pub fn initial_board(&self) -> &ChessBoard {
+        &self.initial_board
+    }
+
+    /// Returns the current position.
+    pub fn board(&self) -> &ChessBoard {
+        &self.board
+    }
+
+    /// Returns moves played from the initial position.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Validates and plays a move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Game, Move};
+    ///
+    /// let mut game = Game::default();
+    ///
+    /// assert!(game.push(&Move::from_string("e2e4").unwrap()).is_ok());
+    /// assert!(game.push(&Move::from_string("e7e4").unwrap()).is_err());
+    /// assert_eq!(game.moves().len(), 1);
+    /// ```
+    pub fn push(&mut self, m: &Move) -> Result<(), IllegalMoveError> {
+        let board = self.board.validate_and_apply_move(m)?;
+
+        self.boards.push(self.board);
+        self.moves.push(*m);
+        self.hashes.push(Game::repetition_hash(&board));
+        self.board = board;
+
+        Ok(())
+    }
+
+    /// Takes back the last move and returns it, or None if no move was played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Game, Move};
+    ///
+    /// let mut game = Game::default();
+    /// assert_eq!(game.pop(), None);
+    ///
+    /// game.push(&Move::from_string("e2e4").unwrap()).unwrap();
+    /// assert_eq!(game.pop(), Some(Move::from_string("e2e4").unwrap()));
+    /// assert_eq!(game.board(), &ChessBoard::STANDARD);
+    /// ```
+    pub fn pop(&mut self) -> Option<Move> {
+        let m = self.moves.pop()?;
+
+        self.hashes.pop();
+        if let Some(board) = self.boards.pop() {
+            self.board = board;
+        }
+
+        Some(m)
+    }
+
+    /// Returns how many times the current position occurred in the game,
+    /// the current occurrence included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Game, Move};
+    ///
+    /// let mut game = Game::default();
+    /// assert_eq!(game.repetitions(), 1);
+    ///
+    /// // en-passant target after e2e4 does not make a difference, as there is no capture possible
+    /// for m in ["e2e4", "g8f6", "g1f3", "f6g8", "f3g1", "g8f6", "g1f3", "f6g8", "f3g1"] {
+    ///     game.push(&Move::from_string(m).unwrap()).unwrap();
+    /// }
+    ///
+    /// assert_eq!(game.repetitions(), 3);
+    /// ```
+    pub fn repetitions(&self) -> usize {
+        let Some((&hash, history)) = self.hashes.split_last() else {
+            return 0;
+        };
+
+        // only positions since the last capture or pawn move may repeat,
+        // and only those with the same side to move
+        history
+            .iter()
+            .rev()
+            .take(self.board.half_move_clock)
+            .skip(1)
+            .step_by(2)
+            .filter(|h| **h == hash)
+            .count()
+            + 1
+    }
+
+    /// Checks if the current position occurred at least three times.
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.repetitions() >= 3
+    }
+
+    /// Checks if the current position occurred at least five times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Game, Move, Outcome};
+    ///
+    /// let mut game = Game::default();
+    ///
+    /// for _ in 0..4 {
+    ///     for m in ["b1c3", "b8c6", "c3b1", "c6b8"] {
+    ///         game.push(&Move::from_string(m).unwrap()).unwrap();
+    ///     }
+    /// }
+    ///
+    /// assert!(game.is_fivefold_repetition());
+    /// assert_eq!(game.outcome(), Some(Outcome::FivefoldRepetition));
+    /// ```
+    pub fn is_fivefold_repetition(&self) -> bool {
+        self.repetitions() >= 5
+    }
+
+    /// Checks if a draw may be claimed by threefold repetition or by the fifty-move rule.
+    pub fn can_claim_draw(&self) -> bool {
+        self.board.half_move_clock >= 100 || self.is_threefold_repetition()
+    }
+
+    /// Returns outcome of the game if it is over, or None if the game goes on.
+    ///
+    /// Extends [ChessBoard::outcome()] with repetition rules.
+    /// Claimable draws ([Outcome::FiftyMoveRule], [Outcome::ThreefoldRepetition])
+    /// are reported only if no other outcome applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Game, Move, Outcome};
+    ///
+    /// let mut game = Game::default();
+    /// assert_eq!(game.outcome(), None);
+    ///
+    /// for m in ["g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8"] {
+    ///     game.push(&Move::from_string(m).unwrap()).unwrap();
+    /// }
+    ///
+    /// assert_eq!(game.outcome(), Some(Outcome::ThreefoldRepetition));
+    /// ```
+    pub fn outcome(&self) -> Option<Outcome> {
+        let outcome = self.board.outcome();
+
+        if outcome.is_some_and(|o| o != Outcome::FiftyMoveRule) {
+            return outcome;
+        }
+
+        if self.is_fivefold_repetition() {
+            return Some(Outcome::FivefoldRepetition);
+        }
+
+        if outcome.is_some() {
+            return outcome;
+        }
+
+        if self.is_threefold_repetition() {
+            return Some(Outcome::ThreefoldRepetition);
+        }
+
+        None
+    }
+
+    /// Hash of a position for repetition detection.
+    /// En-Passant target is considered only if en-passant capture is legal.
+    fn repetition_hash(board: &ChessBoard) -> u64 {
+        let Some(target) = board.en_passant_target else {
+            return board.hash();
+        };
+
+        let pawns = board.pieces[*board.next_move][*Piece::Pawn];
+        if board
+            .legal_moves()
+            .iter()
+            .any(|m| m.to == target && pawns.has_bit(m.from))
+        {
+            board.hash()
+        } else {
+            board.hash() ^ Zobrist::Z.en_passant(target)
+        }
+    }
+}
@@ -1,10 +1,11 @@
-use crate::{BitBoard, ChessBoard, Color, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveGeneration, MoveList, Piece};
 
 use super::generator_bishop::GeneratorBishop;
 use super::generator_king::GeneratorKing;
 use super::generator_knight::GeneratorKnight;
 use super::generator_pawn::GeneratorPawn;
 use super::generator_rook::GeneratorRook;
+use super::move_targets::MoveTargets;
 
 /// Chess moves generator.
 /// Can generate attacks (BitBoard) and Moves for all Chess pieces.
@@ -36,7 +37,7 @@ use super::generator_rook::GeneratorRook;
 /// board = board.validate_and_apply_move(&Move::from_string("b1c3").unwrap()).unwrap();
 ///
 /// assert_eq!(
-///     board.pieces,
+///     board.piece_bitboards(),
 ///     ChessBoard::from_string("
 ///       a b c d e f g h
 ///     8 r n b q k b n r 8
@@ -48,7 +49,7 @@ use super::generator_rook::GeneratorRook;
 ///     2 P P P P P P P P 2
 ///     1 R - B Q K B N R 1
 ///       a b c d e f g h
-///     ").unwrap().pieces
+///     ").unwrap().piece_bitboards()
 /// );
 ///
 /// assert!(board.validate_and_apply_move(&Move::from_string("a1a8").unwrap()).is_err());
@@ -158,17 +159,62 @@ impl Generator {
     /// let moves = board.legal_moves();
     /// assert_eq!(moves.len(), 20);
     /// ```
-    pub(crate) fn legal_moves(&self, board: &ChessBoard) -> Vec<Move> {
-        let mut v = Vec::new();
+    pub(crate) fn legal_moves(&self, board: &ChessBoard) -> MoveList {
+        let mut moves = MoveList::new();
+        self.legal_moves_into(board, &mut moves);
+        moves
+    }
+
+    /// Generate all legal moves for a board into a list.
+    /// The list is cleared first.
+    pub(crate) fn legal_moves_into(&self, board: &ChessBoard, moves: &mut MoveList) {
+        moves.clear();
+        self.legal_moves_with(board, MoveGeneration::All, &mut |m| moves.push(m));
+    }
+
+    /// Generates all legal moves.
+    ///
+    /// Checkers and pinned pieces are computed up front, so the piece generators
+    /// emit only legal moves and no move needs to be applied to be validated,
+    /// except rare en-passant captures.
+    pub(crate) fn legal_moves_with(
+        &self,
+        board: &ChessBoard,
+        generation: MoveGeneration,
+        f: &mut impl FnMut(Move),
+    ) {
+        self.generate_moves(board, &MoveTargets::legal(board, generation), f);
+    }
+
+    /// Returns the legal move equal to the given move, with details of the move.
+    /// Only moves of the piece standing at the source field are generated.
+    pub(crate) fn legal_move(&self, board: &ChessBoard, m: &Move) -> Option<Move> {
+        let (color, piece) = board.piece_at(m.from)?;
+        if color != board.next_move {
+            return None;
+        }
 
-        self.moves(board, &mut |m| {
-            let new_board = board.apply_move(&m);
-            if !self.is_opponent_king_under_check(&new_board) {
-                v.push(m);
+        let targets = MoveTargets::legal(board, MoveGeneration::All);
+        let mut found = None;
+        let f = &mut |mm: Move| {
+            if mm == *m {
+                found = Some(mm);
             }
-        });
+        };
 
-        v
+        match piece {
+            Piece::King => self.generator_king.generate_moves(board, &targets, f),
+            Piece::Queen => {
+                self.generator_rook.generate_moves(board, &targets, f);
+                self.generator_bishop.generate_moves(board, &targets, f);
+            }
+            Piece::Bishop => self.generator_bishop.generate_moves(board, &targets, f),
+            Piece::Knight => self.generator_knight.generate_moves(board, &targets, f),
+            Piece::Rook => self.generator_rook.generate_moves(board, &targets, f),
+            Piece::Pawn => self.generator_pawn.generate_moves(board, &targets, f),
+        }
+
+        found
     }
 
     /// Generates all pseudo-legal moves.
@@ -201,11 +247,16 @@ impl Generator {
     /// assert_eq!(board.legal_moves().len(), 16);
     /// ```
     pub(crate) fn moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
-        self.generator_rook.generate_moves(board, f);
-        self.generator_bishop.generate_moves(board, f);
-        self.generator_pawn.generate_moves(board, f);
-        self.generator_knight.generate_moves(board, f);
-        self.generator_king.generate_moves(board, f);
+        self.generate_moves(board, &MoveTargets::pseudo_legal(board), f);
+    }
+
+    /// Generates moves of all pieces to the targets.
+    fn generate_moves(&self, board: &ChessBoard, targets: &MoveTargets, f: &mut impl FnMut(Move)) {
+        self.generator_rook.generate_moves(board, targets, f);
+        self.generator_bishop.generate_moves(board, targets, f);
+        self.generator_pawn.generate_moves(board, targets, f);
+        self.generator_knight.generate_moves(board, targets, f);
+        self.generator_king.generate_moves(board, targets, f);
     }
 
     /// Checks if opponent king is under check.
@@ -305,6 +356,175 @@ impl Generator {
         false
     }
 
+    /// Returns pieces of a side attacking a field.
+    pub(crate) fn attackers_to(&self, board: &ChessBoard, i: Index, color: Color) -> BitBoard {
+        self.attackers_to_occupied(board, i, color, board.all_pieces())
+    }
+
+    /// Returns pieces of a side attacking a field, sliding pieces are blocked by given occupancy.
+    pub(crate) fn attackers_to_occupied(
+        &self,
+        board: &ChessBoard,
+        i: Index,
+        color: Color,
+        all_pieces: BitBoard,
+    ) -> BitBoard {
+        let pieces = &board.pieces[*color];
+
+        (pieces[*Piece::Pawn] & self.generator_pawn.attacks_cache[*color.opponent()][*i])
+            | (pieces[*Piece::Knight] & self.generator_knight.attacks_cache[*i])
+            | (pieces[*Piece::King] & self.generator_king.attacks_cache[*i])
+            | ((pieces[*Piece::Rook] | pieces[*Piece::Queen])
+                & self.generator_rook.attacks(i, all_pieces))
+            | ((pieces[*Piece::Bishop] | pieces[*Piece::Queen])
+                & self.generator_bishop.attacks(i, all_pieces))
+    }
+
+    /// Returns pieces of the color of the king at the index,
+    /// which are the only blockers between the king and sliding pieces of the opponent.
+    pub(crate) fn pinned(&self, board: &ChessBoard, king: Index, opponent: Color) -> BitBoard {
+        self.blockers(board, king, opponent) & board.pieces(opponent.opponent())
+    }
+
+    /// Returns pieces of any color which are the only blockers
+    /// between a field and sliding pieces of a side.
+    pub(crate) fn blockers(&self, board: &ChessBoard, i: Index, color: Color) -> BitBoard {
+        let pieces = &board.pieces[*color];
+        let all_pieces = board.all_pieces();
+
+        let mut snipers = (self.rook_attacks(i, BitBoard::EMPTY)
+            & (pieces[*Piece::Rook] | pieces[*Piece::Queen]))
+            | (self.bishop_attacks(i, BitBoard::EMPTY)
+                & (pieces[*Piece::Bishop] | pieces[*Piece::Queen]));
+
+        let mut blockers = BitBoard::EMPTY;
+        while let (Some(sniper), tmp) = snipers.bitpop() {
+            snipers = tmp;
+            let b = BitBoard::between(i, sniper) & all_pieces;
+            if b.popcnt() == 1 {
+                blockers |= b;
+            }
+        }
+
+        blockers
+    }
+
+    /// Returns attacks of a king at a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Generator, Index};
+    ///
+    /// assert_eq!(Generator::G.king_attacks(Index::A1), Index::A2 | Index::B1 | Index::B2);
+    /// assert_eq!(Generator::G.king_attacks(Index::E4).popcnt(), 8);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn king_attacks(&self, i: Index) -> BitBoard {
+        self.generator_king.attacks_cache[*i]
+    }
+
+    /// Returns attacks of a knight at a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Generator, Index};
+    ///
+    /// assert_eq!(Generator::G.knight_attacks(Index::A1), Index::B3 | Index::C2);
+    /// assert_eq!(Generator::G.knight_attacks(Index::E4).popcnt(), 8);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn knight_attacks(&self, i: Index) -> BitBoard {
+        self.generator_knight.attacks_cache[*i]
+    }
+
+    /// Returns attacks of a pawn of a color at a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Color, Generator, Index};
+    ///
+    /// assert_eq!(Generator::G.pawn_attacks(Color::White, Index::E4), Index::D5 | Index::F5);
+    /// assert_eq!(Generator::G.pawn_attacks(Color::Black, Index::A4), Index::B3.as_bitboard());
+    /// assert_eq!(Generator::G.pawn_attacks(Color::White, Index::A8), BitBoard::EMPTY);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn pawn_attacks(&self, color: Color, i: Index) -> BitBoard {
+        self.generator_pawn.attacks_cache[*color][*i]
+    }
+
+    /// Returns attacks of a rook at a field, blocked by the occupancy.
+    /// Attacks include the blocking pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Generator, Index};
+    ///
+    /// assert_eq!(
+    ///     Generator::G.rook_attacks(Index::A1, BitBoard::EMPTY),
+    ///     (BitBoard::FILE_A | BitBoard::RANK_1) ^ Index::A1
+    /// );
+    /// assert_eq!(
+    ///     Generator::G.rook_attacks(Index::A1, Index::A2 | Index::C1 | Index::H8),
+    ///     Index::A2 | Index::B1 | Index::C1
+    /// );
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn rook_attacks(&self, i: Index, occupancy: BitBoard) -> BitBoard {
+        self.generator_rook.attacks(i, occupancy)
+    }
+
+    /// Returns attacks of a bishop at a field, blocked by the occupancy.
+    /// Attacks include the blocking pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Generator, Index};
+    ///
+    /// assert_eq!(
+    ///     Generator::G.bishop_attacks(Index::A1, BitBoard::EMPTY),
+    ///     BitBoard::A1H8[7] ^ Index::A1
+    /// );
+    /// assert_eq!(
+    ///     Generator::G.bishop_attacks(Index::A1, Index::C3 | Index::D4),
+    ///     Index::B2 | Index::C3
+    /// );
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn bishop_attacks(&self, i: Index, occupancy: BitBoard) -> BitBoard {
+        self.generator_bishop.attacks(i, occupancy)
+    }
+
+    /// Returns attacks of a queen at a field, blocked by the occupancy.
+    /// Attacks include the blocking pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{BitBoard, Generator, Index};
+    ///
+    /// assert_eq!(Generator::G.queen_attacks(Index::D4, BitBoard::EMPTY).popcnt(), 27);
+    /// assert_eq!(
+    ///     Generator::G.queen_attacks(Index::D4, BitBoard::EMPTY),
+    ///     Generator::G.rook_attacks(Index::D4, BitBoard::EMPTY)
+    ///         | Generator::G.bishop_attacks(Index::D4, BitBoard::EMPTY)
+    /// );
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn queen_attacks(&self, i: Index, occupancy: BitBoard) -> BitBoard {
+        self.rook_attacks(i, occupancy) | self.bishop_attacks(i, occupancy)
+    }
+
     /// Checks if BitMask is under attack by a side.
     ///
     /// # Examples
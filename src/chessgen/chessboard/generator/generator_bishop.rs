@@ -1,4 +1,6 @@
-use crate::{BitBoard, ChessBoard, Color, Index, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveKind, Piece};
+
+use super::move_targets::MoveTargets;
 
 /// magic constants
 /// See: [ChessProgramming Magic Bitboards](https://www.chessprogramming.org/Magic_Bitboards)
@@ -247,7 +249,7 @@ impl GeneratorBishop {
     }
 
     /// Generate attacks for one piece.
-    fn attacks(&self, i: Index, all_pieces: BitBoard) -> BitBoard {
+    pub(super) fn attacks(&self, i: Index, all_pieces: BitBoard) -> BitBoard {
         // use magic multipliers to get occupancy state index
 
         let index_a1h8 =
@@ -276,16 +278,20 @@ impl GeneratorBishop {
     }
 
     /// Generate moves.
-    pub(super) fn generate_moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
+    pub(super) fn generate_moves(
+        &self,
+        board: &ChessBoard,
+        targets: &MoveTargets,
+        f: &mut impl FnMut(Move),
+    ) {
         let all_pieces = board.all_pieces();
-        let board_available = board.board_to_attack();
 
-        for p in [*Piece::Bishop, *Piece::Queen] {
-            let mut pieces = board.pieces[*board.next_move][p];
+        for p in [Piece::Bishop, Piece::Queen] {
+            let mut pieces = board.pieces[*board.next_move][*p];
 
             while let (Some(i), tmp) = pieces.bitpop() {
                 pieces = tmp;
-                let mut moves = self.attacks(i, all_pieces) & board_available;
+                let mut moves = self.attacks(i, all_pieces) & targets.from(p, i);
 
                 while let (Some(t), tmp) = moves.bitpop() {
                     moves = tmp;
@@ -293,6 +299,9 @@ impl GeneratorBishop {
                         from: i,
                         to: t,
                         promotion: None,
+                        piece: Some(p),
+                        captured: board.opponent_piece_at(t),
+                        kind: MoveKind::Normal,
                     });
                 }
             }
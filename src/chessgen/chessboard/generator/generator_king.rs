@@ -1,21 +1,7 @@
-use crate::{BitBoard, ChessBoard, Color, Index, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveKind, Piece};
 
 use super::generator::Generator;
-
-const WHITE_CASTLING_OO_EMPTY: BitBoard = BitBoard::from_index_array(&[Index::F1, Index::G1]);
-const WHITE_CASTLING_OO_ATTACKS: BitBoard =
-    BitBoard::from_index_array(&[Index::E1, Index::F1, Index::G1]);
-const WHITE_CASTLING_OOO_EMPTY: BitBoard =
-    BitBoard::from_index_array(&[Index::B1, Index::C1, Index::D1]);
-const WHITE_CASTLING_OOO_ATTACKS: BitBoard =
-    BitBoard::from_index_array(&[Index::C1, Index::D1, Index::E1]);
-const BLACK_CASTLING_OO_EMPTY: BitBoard = BitBoard::from_index_array(&[Index::F8, Index::G8]);
-const BLACK_CASTLING_OO_ATTACKS: BitBoard =
-    BitBoard::from_index_array(&[Index::E8, Index::F8, Index::G8]);
-const BLACK_CASTLING_OOO_EMPTY: BitBoard =
-    BitBoard::from_index_array(&[Index::B8, Index::C8, Index::D8]);
-const BLACK_CASTLING_OOO_ATTACKS: BitBoard =
-    BitBoard::from_index_array(&[Index::C8, Index::D8, Index::E8]);
+use super::move_targets::MoveTargets;
 
 /// Moves generator for a King.
 #[derive(Debug)]
@@ -63,85 +49,103 @@ impl GeneratorKing {
     }
 
     /// Generate moves.
-    pub(super) fn generate_moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
-        let Some(from) = board.pieces[*board.next_move][*Piece::King].bitscan() else {
+    pub(super) fn generate_moves(
+        &self,
+        board: &ChessBoard,
+        targets: &MoveTargets,
+        f: &mut impl FnMut(Move),
+    ) {
+        let Some(from) = targets.king else {
             return;
         };
 
-        let mut moves = self.attacks_cache[*from] & board.board_to_attack();
+        let mut moves = self.attacks_cache[*from] & targets.from(Piece::King, from);
+
+        // king must not hide from a sliding piece behind itself
+        let occupancy = board.all_pieces() ^ from;
+        let opponent = board.next_move.opponent();
 
         while let (Some(to), tmp) = moves.bitpop() {
             moves = tmp;
+            if targets.legal
+                && Generator::G.attackers_to_occupied(board, to, opponent, occupancy)
+                    != BitBoard::EMPTY
+            {
+                continue;
+            }
             f(Move {
                 from,
                 to,
                 promotion: None,
+                piece: Some(Piece::King),
+                captured: board.opponent_piece_at(to),
+                kind: MoveKind::Normal,
             })
         }
 
+        if !targets.castling {
+            return;
+        }
+
+        let mut castle = |to: Index| {
+            let m = Move {
+                from,
+                to,
+                promotion: None,
+                piece: Some(Piece::King),
+                captured: None,
+                kind: MoveKind::Castling,
+            };
+
+            // when generating checks, castling must give check by the rook
+            if targets.opponent_king.is_none() || board.apply_move(&m).is_my_king_under_check() {
+                f(m);
+            }
+        };
+
         let all_pieces = board.all_pieces();
+        let color = board.next_move;
 
-        match board.next_move {
-            Color::White => {
-                if board.castling_options[*board.next_move][*Piece::King]
-                    && (all_pieces & WHITE_CASTLING_OO_EMPTY) == BitBoard::EMPTY
-                    && !Generator::G.is_bitmask_under_attack(
-                        board,
-                        Color::Black,
-                        WHITE_CASTLING_OO_ATTACKS,
-                    )
-                {
-                    f(Move {
-                        from,
-                        to: Index::G1,
-                        promotion: None,
-                    })
-                }
-                if board.castling_options[*board.next_move][*Piece::Queen]
-                    && (all_pieces & WHITE_CASTLING_OOO_EMPTY) == BitBoard::EMPTY
-                    && !Generator::G.is_bitmask_under_attack(
-                        board,
-                        Color::Black,
-                        WHITE_CASTLING_OOO_ATTACKS,
-                    )
-                {
-                    f(Move {
-                        from,
-                        to: Index::C1,
-                        promotion: None,
-                    })
-                }
+        for side in [Piece::King, Piece::Queen] {
+            let Some(rook_from) = board.castling_rook(color, side) else {
+                continue;
+            };
+            if !board.pieces[*color][*Piece::Rook].has_bit(rook_from) {
+                continue;
             }
-            Color::Black => {
-                if board.castling_options[*board.next_move][*Piece::King]
-                    && (all_pieces & BLACK_CASTLING_OO_EMPTY) == BitBoard::EMPTY
-                    && !Generator::G.is_bitmask_under_attack(
-                        board,
-                        Color::White,
-                        BLACK_CASTLING_OO_ATTACKS,
-                    )
-                {
-                    f(Move {
-                        from,
-                        to: Index::G8,
-                        promotion: None,
-                    })
-                }
-                if board.castling_options[*board.next_move][*Piece::Queen]
-                    && (all_pieces & BLACK_CASTLING_OOO_EMPTY) == BitBoard::EMPTY
-                    && !Generator::G.is_bitmask_under_attack(
-                        board,
-                        Color::White,
-                        BLACK_CASTLING_OOO_ATTACKS,
-                    )
+
+            let (king_to, rook_to) = ChessBoard::castling_targets(color, side);
+
+            // fields passed by the king and the rook must be empty, except of themselves
+            let king_path = BitBoard::between(from, king_to) | king_to;
+            let rook_path = BitBoard::between(rook_from, rook_to) | rook_to;
+            if (king_path | rook_path) & all_pieces & !(from | rook_from) != BitBoard::EMPTY {
+                continue;
+            }
+
+            // king must not be under check on its way,
+            // the castling rook does not hide it from a sliding piece behind the rook
+            let occupancy = all_pieces ^ rook_from;
+            let mut path = king_path | from;
+            let mut attacked = false;
+            while let (Some(i), tmp) = path.bitpop() {
+                path = tmp;
+                if Generator::G.attackers_to_occupied(board, i, opponent, occupancy)
+                    != BitBoard::EMPTY
                 {
-                    f(Move {
-                        from,
-                        to: Index::C8,
-                        promotion: None,
-                    })
+                    attacked = true;
+                    break;
                 }
             }
+            if attacked {
+                continue;
+            }
+
+            castle(if board.is_chess960() {
+                rook_from
+            } else {
+                king_to
+            });
         }
     }
 }
@@ -1,4 +1,6 @@
-use crate::{BitBoard, ChessBoard, Color, Index, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveKind, Piece};
+
+use super::move_targets::MoveTargets;
 
 /// Moves generator for a Knight.
 #[derive(Debug)]
@@ -48,12 +50,17 @@ impl GeneratorKnight {
     }
 
     /// Generate moves.
-    pub(super) fn generate_moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
+    pub(super) fn generate_moves(
+        &self,
+        board: &ChessBoard,
+        targets: &MoveTargets,
+        f: &mut impl FnMut(Move),
+    ) {
         let mut pieces = board.pieces[*board.next_move][*Piece::Knight];
 
         while let (Some(from), tmp) = pieces.bitpop() {
             pieces = tmp;
-            let mut moves = self.attacks_cache[*from] & board.board_to_attack();
+            let mut moves = self.attacks_cache[*from] & targets.from(Piece::Knight, from);
 
             while let (Some(to), tmp) = moves.bitpop() {
                 moves = tmp;
@@ -61,6 +68,9 @@ impl GeneratorKnight {
                     from,
                     to,
                     promotion: None,
+                    piece: Some(Piece::Knight),
+                    captured: board.opponent_piece_at(to),
+                    kind: MoveKind::Normal,
                 })
             }
         }
@@ -1,4 +1,7 @@
-use crate::{BitBoard, ChessBoard, Color, Index, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveKind, Piece};
+
+use super::generator::Generator;
+use super::move_targets::MoveTargets;
 
 /// Moves generator for a Pawn.
 #[derive(Debug)]
@@ -39,7 +42,12 @@ impl GeneratorPawn {
     }
 
     /// Generate moves.
-    pub(super) fn generate_moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
+    pub(super) fn generate_moves(
+        &self,
+        board: &ChessBoard,
+        targets: &MoveTargets,
+        f: &mut impl FnMut(Move),
+    ) {
         let empty_board = !board.all_pieces();
         let mut pieces = board.pieces[*board.next_move][*Piece::Pawn];
 
@@ -72,47 +80,61 @@ impl GeneratorPawn {
                 }
             };
 
+            moves &= targets.from(Piece::Pawn, from);
+
             while let (Some(to), tmp) = moves.bitpop() {
                 moves = tmp;
+                let captured = board.opponent_piece_at(to);
+
                 if to > Index::H7 || to < Index::A2 {
                     // promotion
-                    f(Move {
-                        from,
-                        to,
-                        promotion: Some(Piece::Bishop),
-                    });
-                    f(Move {
-                        from,
-                        to,
-                        promotion: Some(Piece::Knight),
-                    });
-                    f(Move {
-                        from,
-                        to,
-                        promotion: Some(Piece::Queen),
-                    });
-                    f(Move {
-                        from,
-                        to,
-                        promotion: Some(Piece::Rook),
-                    });
+                    for promotion in [Piece::Bishop, Piece::Knight, Piece::Queen, Piece::Rook] {
+                        f(Move {
+                            from,
+                            to,
+                            promotion: Some(promotion),
+                            piece: Some(Piece::Pawn),
+                            captured,
+                            kind: MoveKind::Normal,
+                        });
+                    }
                 } else {
                     f(Move {
                         from,
                         to,
                         promotion: None,
+                        piece: Some(Piece::Pawn),
+                        captured,
+                        kind: if from.distance_to(to) == 16 {
+                            MoveKind::DoublePawnPush
+                        } else {
+                            MoveKind::Normal
+                        },
                     });
                 }
             }
 
-            if let Some(en_passant_target) = board.en_passant_target {
+            if targets.en_passant
+                && let Some(en_passant_target) = board.en_passant_target
+            {
                 moves = attacks & en_passant_target;
                 if let Some(to) = moves.bitscan() {
-                    f(Move {
+                    let m = Move {
                         from,
                         to,
                         promotion: None,
-                    });
+                        piece: Some(Piece::Pawn),
+                        captured: Some(Piece::Pawn),
+                        kind: MoveKind::EnPassant,
+                    };
+
+                    // en-passant removes two pieces from the line of the king,
+                    // rare enough to be validated by applying the move
+                    if !targets.legal
+                        || !Generator::G.is_opponent_king_under_check(&board.apply_move(&m))
+                    {
+                        f(m);
+                    }
                 }
             }
         }
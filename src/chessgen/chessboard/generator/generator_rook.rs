@@ -1,4 +1,6 @@
-use crate::{BitBoard, ChessBoard, Color, Index, Move, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Move, MoveKind, Piece};
+
+use super::move_targets::MoveTargets;
 
 /// magic constants
 /// See: [ChessProgramming Magic Bitboards](https://www.chessprogramming.org/Magic_Bitboards)
@@ -179,7 +181,7 @@ impl GeneratorRook {
     }
 
     /// Generate attacks for one piece.
-    fn attacks(&self, i: Index, all_pieces: BitBoard) -> BitBoard {
+    pub(super) fn attacks(&self, i: Index, all_pieces: BitBoard) -> BitBoard {
         // use magic multipliers to get occupancy state index
         let state_rank = (all_pieces & self.rank_mask[*i]).state >> self.rank_shift[i.index];
         let state_file =
@@ -207,16 +209,20 @@ impl GeneratorRook {
     }
 
     /// Generate moves.
-    pub(super) fn generate_moves(&self, board: &ChessBoard, f: &mut impl FnMut(Move)) {
+    pub(super) fn generate_moves(
+        &self,
+        board: &ChessBoard,
+        targets: &MoveTargets,
+        f: &mut impl FnMut(Move),
+    ) {
         let all_pieces = board.all_pieces();
-        let board_available = board.board_to_attack();
 
-        for p in [*Piece::Rook, *Piece::Queen] {
-            let mut pieces = board.pieces[*board.next_move][p];
+        for p in [Piece::Rook, Piece::Queen] {
+            let mut pieces = board.pieces[*board.next_move][*p];
 
             while let (Some(i), tmp) = pieces.bitpop() {
                 pieces = tmp;
-                let mut moves = self.attacks(i, all_pieces) & board_available;
+                let mut moves = self.attacks(i, all_pieces) & targets.from(p, i);
 
                 while let (Some(t), tmp) = moves.bitpop() {
                     moves = tmp;
@@ -224,6 +230,9 @@ impl GeneratorRook {
                         from: i,
                         to: t,
                         promotion: None,
+                        piece: Some(p),
+                        captured: board.opponent_piece_at(t),
+                        kind: MoveKind::Normal,
                     });
                 }
             }
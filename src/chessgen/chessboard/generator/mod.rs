@@ -1,6 +1,7 @@
 pub use self::error::IllegalMoveError;
 pub use self::generator::Generator;
 pub use self::perft::PerfT;
+pub use self::perft_stats::PerfTStats;
 pub use self::zobrist::Zobrist;
 
 mod error;
@@ -11,5 +12,8 @@ mod generator_king;
 mod generator_knight;
 mod generator_pawn;
 mod generator_rook;
+mod move_targets;
 mod perft;
+mod perft_stats;
 mod zobrist;
+mod zobrist_polyglot;
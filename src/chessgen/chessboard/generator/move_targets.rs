@@ -0,0 +1,164 @@
+use crate::{BitBoard, ChessBoard, Color, Index, MoveGeneration, Piece};
+
+use super::generator::Generator;
+
+/// Fields to which the piece generators emit moves.
+/// Computed once per position, so the generators mask their targets
+/// instead of validating every generated move.
+#[derive(Debug, Copy, Clone)]
+pub(super) struct MoveTargets {
+    /// King of the side to move.
+    pub king: Option<Index>,
+    /// Fields to which pieces may move: [piece].
+    /// When in check only the checking piece and fields blocking the check, none in double check.
+    /// King moves are further validated against attacks of the opponent.
+    pub targets: [BitBoard; Piece::VALUES.len()],
+    /// Pieces of the side to move pinned to their king, they may move only along the pin line.
+    pub pinned: BitBoard,
+    /// Opponent king, when generating checks only.
+    pub opponent_king: Option<Index>,
+    /// Pieces discovering a check of the opponent king by moving off the line to it.
+    pub discoverers: BitBoard,
+    /// Fields to which discoverers may move to discover a check: [piece].
+    pub discoverer_targets: [BitBoard; Piece::VALUES.len()],
+    /// Generate en-passant captures.
+    pub en_passant: bool,
+    /// Generate castling.
+    pub castling: bool,
+    /// Generate only legal moves - king does not move to attacked fields
+    /// and en-passant captures and checking castling moves are validated.
+    pub legal: bool,
+}
+
+impl MoveTargets {
+    /// Targets of all pseudo-legal moves.
+    pub(super) fn pseudo_legal(board: &ChessBoard) -> Self {
+        MoveTargets {
+            king: board.my_king(),
+            targets: [board.board_to_attack(); Piece::VALUES.len()],
+            pinned: BitBoard::EMPTY,
+            opponent_king: None,
+            discoverers: BitBoard::EMPTY,
+            discoverer_targets: [BitBoard::EMPTY; Piece::VALUES.len()],
+            en_passant: true,
+            castling: true,
+            legal: false,
+        }
+    }
+
+    /// Targets of legal moves of a generation type.
+    pub(super) fn legal(board: &ChessBoard, generation: MoveGeneration) -> Self {
+        let g = &Generator::G;
+        let opponent = board.next_move.opponent();
+
+        let mut evasions = BitBoard::UNIVERSE;
+        let mut pinned = BitBoard::EMPTY;
+        let mut in_check = false;
+
+        if let Some(king) = board.my_king() {
+            let checkers = g.attackers_to(board, king, opponent);
+            in_check = checkers != BitBoard::EMPTY;
+            evasions = match checkers.popcnt() {
+                0 => BitBoard::UNIVERSE,
+                1 => checkers | BitBoard::between(king, checkers.bitscan().unwrap()),
+                _ => BitBoard::EMPTY,
+            };
+            pinned = g.pinned(board, king, opponent);
+        }
+
+        let empty = !board.all_pieces();
+        let promotions = match board.next_move {
+            Color::White => BitBoard::RANK_8,
+            Color::Black => BitBoard::RANK_1,
+        };
+
+        let (pieces_targets, pawn_targets) = match generation {
+            MoveGeneration::All => (board.board_to_attack(), board.board_to_attack()),
+            MoveGeneration::Evasions if in_check => {
+                (board.board_to_attack(), board.board_to_attack())
+            }
+            MoveGeneration::Evasions => (BitBoard::EMPTY, BitBoard::EMPTY),
+            MoveGeneration::Captures => (
+                board.opponent_pieces(),
+                board.opponent_pieces() | (empty & promotions),
+            ),
+            MoveGeneration::Quiets | MoveGeneration::QuietChecks => (empty, empty & !promotions),
+        };
+
+        let mut targets = [pieces_targets & evasions; Piece::VALUES.len()];
+        targets[*Piece::Pawn] = pawn_targets & evasions;
+        // king escapes the check on its own
+        targets[*Piece::King] = pieces_targets;
+
+        let mut move_targets = MoveTargets {
+            king: board.my_king(),
+            targets,
+            pinned,
+            opponent_king: None,
+            discoverers: BitBoard::EMPTY,
+            discoverer_targets: [BitBoard::EMPTY; Piece::VALUES.len()],
+            en_passant: matches!(
+                generation,
+                MoveGeneration::All | MoveGeneration::Captures | MoveGeneration::Evasions
+            ) && pieces_targets != BitBoard::EMPTY,
+            castling: matches!(
+                generation,
+                MoveGeneration::All | MoveGeneration::Quiets | MoveGeneration::QuietChecks
+            ),
+            legal: true,
+        };
+
+        if generation == MoveGeneration::QuietChecks {
+            move_targets.restrict_to_checks(board);
+        }
+
+        move_targets
+    }
+
+    /// Restricts the targets to fields giving check to the opponent king.
+    fn restrict_to_checks(&mut self, board: &ChessBoard) {
+        let Some(king) = board.opponent_king() else {
+            self.targets = [BitBoard::EMPTY; Piece::VALUES.len()];
+            self.castling = false;
+            return;
+        };
+
+        let g = &Generator::G;
+        let all_pieces = board.all_pieces();
+
+        // pieces moving off the line to the king discover attacks of sliding pieces behind them
+        self.opponent_king = Some(king);
+        self.discoverers = g.blockers(board, king, board.next_move) & board.my_pieces();
+        self.discoverer_targets = self.targets;
+
+        let rook_checks = g.rook_attacks(king, all_pieces);
+        let bishop_checks = g.bishop_attacks(king, all_pieces);
+
+        self.targets[*Piece::King] = BitBoard::EMPTY;
+        self.targets[*Piece::Queen] &= rook_checks | bishop_checks;
+        self.targets[*Piece::Rook] &= rook_checks;
+        self.targets[*Piece::Bishop] &= bishop_checks;
+        self.targets[*Piece::Knight] &= g.knight_attacks(king);
+        self.targets[*Piece::Pawn] &= g.pawn_attacks(board.next_move.opponent(), king);
+    }
+
+    /// Returns fields to which a piece standing at a field may move.
+    #[inline(always)]
+    pub(super) fn from(&self, piece: Piece, from: Index) -> BitBoard {
+        let mut targets = self.targets[*piece];
+
+        if let Some(king) = self.opponent_king
+            && self.discoverers.has_bit(from)
+        {
+            targets |= self.discoverer_targets[*piece] & !BitBoard::line(king, from);
+        }
+
+        if let Some(king) = self.king
+            && self.pinned.has_bit(from)
+        {
+            targets &= BitBoard::line(king, from);
+        }
+
+        targets
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::mpsc::channel;
 use std::thread;
 
-use crate::{BitBoard, ChessBoard, Piece, Zobrist};
+use crate::{ChessBoard, Generator, Move, MoveGeneration};
 
 /// PerfT checker.
 ///
@@ -13,16 +13,17 @@ use crate::{BitBoard, ChessBoard, Piece, Zobrist};
 pub struct PerfT {
     /// PerfT Cache.
     cache: PerfTCache,
-    /// Zobrist hasher.
-    zobrist: Zobrist,
 }
 
 impl PerfT {
+    /// Memory size of one cache entry in bytes.
+    pub const CACHE_ENTRY_SIZE: usize = size_of::<RwLock<PerfTCacheEntry>>();
+
     /// Create a new PerfT checker.
+    /// Cache size is a number of entries, it should be a power of two.
     pub fn new(cache_size: usize) -> Self {
         PerfT {
             cache: PerfTCache::new(cache_size),
-            zobrist: Zobrist::new(),
         }
     }
 
@@ -87,8 +88,7 @@ impl PerfT {
         let num_cpus = thread::available_parallelism()
             .map(|p| p.get())
             .unwrap_or(1);
-        let mut moves = Vec::new();
-        board.legal_moves().into_iter().for_each(|m| moves.push(m));
+        let moves = board.legal_moves();
         let chunk_size = cmp::max(1, moves.len() / num_cpus);
         let shared_self = Arc::new(self);
 
@@ -113,6 +113,36 @@ impl PerfT {
         rx.iter().sum()
     }
 
+    /// Returns count of possible moves up to certain depth for each legal move of the board.
+    /// Moves are in the order of the moves generator, the sum of counts equals to [PerfT::perft_n()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, PerfT};
+    ///
+    /// let perft = PerfT::new(1024);
+    /// let divide = perft.perft_divide(&ChessBoard::STANDARD, 3);
+    ///
+    /// assert_eq!(divide.len(), 20);
+    /// assert!(divide.contains(&(Move::from_string("e2e4").unwrap(), 600)));
+    /// assert!(divide.contains(&(Move::from_string("g1f3").unwrap(), 440)));
+    /// assert_eq!(divide.iter().map(|(_, count)| count).sum::<u64>(), 8_902);
+    ///
+    /// assert!(perft.perft_divide(&ChessBoard::STANDARD, 0).is_empty());
+    /// ```
+    pub fn perft_divide(&self, board: &ChessBoard, depth: usize) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return Vec::new();
+        }
+
+        board
+            .legal_moves()
+            .into_iter()
+            .map(|m| (m, self.perft_n(&board.apply_move(&m), depth - 1)))
+            .collect()
+    }
+
     /// Returns count of possible moves up to certain dept.
     /// Runs the computation in one thread only.
     pub fn perft1(&self, board: &ChessBoard, depth: usize) -> u64 {
@@ -120,37 +150,16 @@ impl PerfT {
             return 1;
         }
 
-        let hash = self.zobrist.hash(board);
+        let hash = board.hash();
         if let Some(count) = self.cache.get(hash, depth) {
             return count;
         }
 
         let mut count = 0u64;
 
-        let attacks = board.attacks(board.next_move.opponent());
-        let is_check = attacks & board.pieces[*board.next_move][*Piece::King] != BitBoard::EMPTY;
-
-        board.moves(&mut |m| {
-            let is_king = board.pieces[*board.next_move][*Piece::King].has_bit(m.from);
-            let is_enpassant = board.pieces[*board.next_move][*Piece::Pawn].has_bit(m.from)
-                && board.en_passant_target.is_some_and(|i| m.to == i);
-
-            // need to validate legality of move only in following cases
-            let need_to_validate = is_king || is_check || attacks.has_bit(m.from) || is_enpassant;
-
-            match depth {
-                1 => {
-                    if !need_to_validate || !board.apply_move(&m).is_opponent_king_under_check() {
-                        count += 1;
-                    }
-                }
-                _ => {
-                    let new_board = board.apply_move(&m);
-                    if !need_to_validate || !new_board.is_opponent_king_under_check() {
-                        count += self.perft1(&new_board, depth - 1);
-                    }
-                }
-            }
+        Generator::G.legal_moves_with(board, MoveGeneration::All, &mut |m| match depth {
+            1 => count += 1,
+            _ => count += self.perft1(&board.apply_move(&m), depth - 1),
         });
 
         self.cache.set(hash, depth, count);
@@ -0,0 +1,222 @@
+use std::ops::AddAssign;
+use std::sync::mpsc::channel;
+use std::{cmp, thread};
+
+use crate::{BitBoard, ChessBoard, Generator, Move, MoveList};
+
+use super::PerfT;
+
+/// PerfT statistics of moves at one depth.
+///
+/// See: [ChessProgramming PerfT Results](https://www.chessprogramming.org/Perft_Results)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PerfTStats {
+    /// Count of moves.
+    pub nodes: u64,
+    /// Captures, including en-passant captures.
+    pub captures: u64,
+    /// En-passant captures.
+    pub en_passants: u64,
+    /// Castling moves.
+    pub castles: u64,
+    /// Promotions.
+    pub promotions: u64,
+    /// Moves giving check.
+    pub checks: u64,
+    /// Checks not given by the moving piece.
+    pub discovered_checks: u64,
+    /// Checks given by two pieces.
+    pub double_checks: u64,
+    /// Moves giving checkmate.
+    pub checkmates: u64,
+}
+
+impl AddAssign for PerfTStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.nodes += rhs.nodes;
+        self.captures += rhs.captures;
+        self.en_passants += rhs.en_passants;
+        self.castles += rhs.castles;
+        self.promotions += rhs.promotions;
+        self.checks += rhs.checks;
+        self.discovered_checks += rhs.discovered_checks;
+        self.double_checks += rhs.double_checks;
+        self.checkmates += rhs.checkmates;
+    }
+}
+
+impl PerfT {
+    /// Returns statistics of moves for each depth from 1 up to the given depth.
+    ///
+    /// Classifying moves is much slower than counting them,
+    /// statistics are not cached and are meant for validation of the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, PerfT, PerfTStats};
+    ///
+    /// let perft = PerfT::new(1024);
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    /// let stats = perft.perft_stats(&board, 3);
+    ///
+    /// assert_eq!(stats.len(), 3);
+    /// assert_eq!(
+    ///     stats[0],
+    ///     PerfTStats { nodes: 48, captures: 8, castles: 2, ..Default::default() }
+    /// );
+    /// assert_eq!(
+    ///     stats[1],
+    ///     PerfTStats { nodes: 2_039, captures: 351, en_passants: 1, castles: 91, checks: 3, ..Default::default() }
+    /// );
+    /// assert_eq!(
+    ///     stats[2],
+    ///     PerfTStats {
+    ///         nodes: 97_862,
+    ///         captures: 17_102,
+    ///         en_passants: 45,
+    ///         castles: 3_162,
+    ///         checks: 993,
+    ///         checkmates: 1,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// let board = ChessBoard::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -").unwrap();
+    /// let stats = perft.perft_stats(&board, 5);
+    /// assert_eq!(
+    ///     stats[3],
+    ///     PerfTStats {
+    ///         nodes: 43_238,
+    ///         captures: 3_348,
+    ///         en_passants: 123,
+    ///         checks: 1_680,
+    ///         discovered_checks: 106,
+    ///         checkmates: 17,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     stats[4],
+    ///     PerfTStats {
+    ///         nodes: 674_624,
+    ///         captures: 52_051,
+    ///         en_passants: 1_165,
+    ///         checks: 52_950,
+    ///         discovered_checks: 1_292,
+    ///         double_checks: 3,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// // check given by the castling rook is not a discovered check
+    /// let board = ChessBoard::from_fen("3k4/8/8/8/8/8/8/R3K3 w Q - 0 1").unwrap();
+    /// assert_eq!(
+    ///     perft.perft_stats(&board, 1)[0],
+    ///     PerfTStats { nodes: 16, castles: 1, checks: 3, ..Default::default() }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     perft.perft_stats(&ChessBoard::STANDARD, 4)[3],
+    ///     PerfTStats { nodes: 197_281, captures: 1_576, checks: 469, checkmates: 8, ..Default::default() }
+    /// );
+    /// ```
+    pub fn perft_stats(&self, board: &ChessBoard, depth: usize) -> Vec<PerfTStats> {
+        let mut stats = vec![PerfTStats::default(); depth];
+        if depth == 0 {
+            return stats;
+        }
+
+        let (tx, rx) = channel();
+        let num_cpus = thread::available_parallelism()
+            .map(|p| p.get())
+            .unwrap_or(1);
+        let moves = board.legal_moves();
+        let chunk_size = cmp::max(1, moves.len() / num_cpus);
+
+        thread::scope(|scope| {
+            for chunk in moves.chunks(chunk_size) {
+                let tx = tx.clone();
+
+                scope.spawn(move || {
+                    let mut stats = vec![PerfTStats::default(); depth];
+                    for m in chunk {
+                        collect_stats(board, m, &mut stats);
+                    }
+                    tx.send(stats).unwrap();
+                });
+            }
+        });
+
+        drop(tx);
+        for chunk_stats in rx.iter() {
+            for (s, c) in stats.iter_mut().zip(chunk_stats) {
+                *s += c;
+            }
+        }
+
+        stats
+    }
+}
+
+/// Classifies a legal move and collects statistics of moves following it.
+/// The first item of stats is the depth of the move.
+fn collect_stats(board: &ChessBoard, m: &Move, stats: &mut [PerfTStats]) {
+    let Some((s, next_stats)) = stats.split_first_mut() else {
+        return;
+    };
+
+    let new_board = board.apply_move(m);
+
+    s.nodes += 1;
+
+    if m.is_en_passant() {
+        s.en_passants += 1;
+    }
+    if m.is_capture() {
+        s.captures += 1;
+    }
+    if m.is_castling() {
+        s.castles += 1;
+    }
+    if m.promotion.is_some() {
+        s.promotions += 1;
+    }
+
+    let checkers = match new_board.my_king() {
+        Some(king) => Generator::G.attackers_to(&new_board, king, board.next_move),
+        None => BitBoard::EMPTY,
+    };
+    let mut legal_moves = MoveList::new();
+    if checkers != BitBoard::EMPTY || !next_stats.is_empty() {
+        new_board.legal_moves_into(&mut legal_moves);
+    }
+
+    if checkers != BitBoard::EMPTY {
+        s.checks += 1;
+
+        // final fields of the moving pieces, castling moves the king and the rook
+        let moved = if m.is_castling() {
+            let (king_to, rook_to) =
+                ChessBoard::castling_targets(board.next_move, ChessBoard::castling_side(m));
+            king_to | rook_to
+        } else {
+            m.to.as_bitboard()
+        };
+        // a double check counts as discovered only if the moving piece does not give check
+        if checkers & moved == BitBoard::EMPTY {
+            s.discovered_checks += 1;
+        }
+        if checkers.popcnt() > 1 {
+            s.double_checks += 1;
+        }
+        if legal_moves.is_empty() {
+            s.checkmates += 1;
+        }
+    }
+
+    for m in &legal_moves {
+        collect_stats(&new_board, m, next_stats);
+    }
+}
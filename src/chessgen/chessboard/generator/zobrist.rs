@@ -1,20 +1,27 @@
-use crate::{ChessBoard, Color, Index, Piece};
+use crate::{BitBoard, ChessBoard, Color, Index, Piece};
+
+use super::zobrist_polyglot::POLYGLOT_RANDOM;
 
 /// Zobrist hash generator for ChessBoard.
-/// This implementation uses deterministic random generator with a fixed seed,
-/// so all instances of Zobrist should be initialized with same unique numbers.
+/// This implementation uses a private deterministic random generator with a fixed seed,
+/// so all instances of Zobrist are initialized with same unique numbers.
+/// The numbers are generated at compile time, without any global side effects.
 ///
 /// See: [ChessProgramming Zobrist Hashing](https://www.chessprogramming.org/Zobrist_Hashing)
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Zobrist {
     /// Unique 64bit IDs for pieces: [color][piece][square].
     pieces: [[[u64; Index::ALL_FIELDS.len()]; Piece::VALUES.len()]; Color::VALUES.len()],
     /// Unique 64bit IDs for castling: [color][piece].
     castling: [[u64; Piece::VALUES.len()]; Color::VALUES.len()],
+    /// Unique 64bit IDs for files of castling rooks other than the corner rooks: [color][piece][file].
+    castling_rook_files: [[[u64; 8]; 2]; Color::VALUES.len()],
     /// Unique 64bit IDs for en_passant Index.
     en_passant: [u64; Index::ALL_FIELDS.len()],
     /// Unique 64bit IDs for side on move.
     side: u64,
+    /// Polyglot compatible hashing.
+    polyglot: bool,
 }
 
 /// Construct a new instance of Zobrist hasher.
@@ -25,42 +32,206 @@ impl Default for Zobrist {
 }
 
 impl Zobrist {
+    /// Zobrist hasher used for ChessBoard hashes.
+    /// It is a constant because const boards like [ChessBoard::STANDARD] are hashed at compile time,
+    /// the keys are therefore generated by a const fn generator.
+    pub(crate) const Z: Zobrist = Zobrist::new();
+
+    /// Seed of the default Zobrist hasher.
+    const DEFAULT_SEED: u64 = 13;
+
     /// Construct a new instance of Zobrist hasher.
-    pub fn new() -> Zobrist {
-        fastrand::seed(13);
+    /// Hashes are the same as [ChessBoard::hash()].
+    pub const fn new() -> Zobrist {
+        Zobrist::with_seed(Zobrist::DEFAULT_SEED)
+    }
+
+    /// Construct a new instance of Zobrist hasher with unique numbers generated from a seed.
+    /// Independent sets of numbers may be used for example to check hash collisions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, Zobrist};
+    ///
+    /// let board = ChessBoard::STANDARD.apply_move(&Move::from_string("e2e4").unwrap());
+    ///
+    /// assert_eq!(Zobrist::new().hash(&board), board.hash());
+    /// assert_eq!(Zobrist::with_seed(1).hash(&board), Zobrist::with_seed(1).hash(&board));
+    /// assert_ne!(Zobrist::with_seed(1).hash(&board), Zobrist::with_seed(2).hash(&board));
+    /// assert_ne!(Zobrist::with_seed(1).hash(&board), board.hash());
+    /// ```
+    pub const fn with_seed(seed: u64) -> Zobrist {
+        let mut state = seed;
 
         let mut pieces =
             [[[0u64; Index::ALL_FIELDS.len()]; Piece::VALUES.len()]; Color::VALUES.len()];
         let mut en_passant = [0u64; Index::ALL_FIELDS.len()];
         let mut castling = [[0u64; Piece::VALUES.len()]; Color::VALUES.len()];
-        let side = fastrand::u64(..);
+        let mut castling_rook_files = [[[0u64; 8]; 2]; Color::VALUES.len()];
+        let side = split_mix(&mut state);
 
-        for color_pieces in &mut pieces {
-            for piece_squares in color_pieces.iter_mut() {
-                for square in piece_squares.iter_mut() {
-                    *square = fastrand::u64(..);
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let mut p = 0;
+            while p < Piece::VALUES.len() {
+                let mut i = 0;
+                while i < Index::ALL_FIELDS.len() {
+                    pieces[c][p][i] = split_mix(&mut state);
+                    i += 1;
                 }
+                p += 1;
             }
+            c += 1;
+        }
+
+        let mut i = 0;
+        while i < Index::ALL_FIELDS.len() {
+            en_passant[i] = split_mix(&mut state);
+            i += 1;
         }
 
-        for square in &mut en_passant {
-            *square = fastrand::u64(..);
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let mut p = 0;
+            while p < Piece::VALUES.len() {
+                castling[c][p] = split_mix(&mut state);
+                p += 1;
+            }
+            c += 1;
         }
 
-        for color_castling in &mut castling {
-            for piece in color_castling.iter_mut() {
-                *piece = fastrand::u64(..);
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let mut p = 0;
+            while p < 2 {
+                let mut file = 0;
+                while file < 8 {
+                    castling_rook_files[c][p][file] = split_mix(&mut state);
+                    file += 1;
+                }
+                p += 1;
             }
+            c += 1;
         }
 
         Zobrist {
             pieces,
             castling,
+            castling_rook_files,
             en_passant,
             side,
+            polyglot: false,
+        }
+    }
+
+    /// Construct a new instance of Zobrist hasher compatible with Polyglot opening books.
+    ///
+    /// Polyglot hash differs in the side on move key, which is used for white,
+    /// and en-passant target is considered only if there is a pawn which could capture it.
+    ///
+    /// See: [Polyglot book format](http://hgm.nubati.net/book_format.html)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, Zobrist};
+    ///
+    /// let polyglot = Zobrist::polyglot();
+    /// let hash = |fen| polyglot.hash(&ChessBoard::from_fen(fen).unwrap());
+    ///
+    /// assert_eq!(polyglot.hash(&ChessBoard::STANDARD), 0x463b96181691fc9c);
+    ///
+    /// assert_eq!(hash("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"), 0x823c9b50fd114196);
+    /// assert_eq!(hash("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2"), 0x0756b94461c50fb0);
+    /// assert_eq!(hash("rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2"), 0x662fafb965db29d4);
+    /// assert_eq!(hash("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3"), 0x22a48b5a8e47ff78);
+    /// assert_eq!(hash("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 0 3"), 0x652a607ca3f242c1);
+    /// assert_eq!(hash("rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 0 4"), 0x00fdd303c946bdd9);
+    /// assert_eq!(hash("rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3"), 0x3c8123ea7b067637);
+    /// assert_eq!(hash("rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 0 4"), 0x5c3f9b829b279560);
+    /// ```
+    pub const fn polyglot() -> Zobrist {
+        let mut pieces =
+            [[[0u64; Index::ALL_FIELDS.len()]; Piece::VALUES.len()]; Color::VALUES.len()];
+        let mut en_passant = [0u64; Index::ALL_FIELDS.len()];
+        let mut castling = [[0u64; Piece::VALUES.len()]; Color::VALUES.len()];
+
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let mut p = 0;
+            while p < Piece::VALUES.len() {
+                // polyglot piece kind: black pawn, white pawn, black knight, white knight, ...
+                let kind = match Piece::VALUES[p] {
+                    Piece::Pawn => 0,
+                    Piece::Knight => 1,
+                    Piece::Bishop => 2,
+                    Piece::Rook => 3,
+                    Piece::Queen => 4,
+                    Piece::King => 5,
+                } * 2
+                    + if c == Color::White as usize { 1 } else { 0 };
+
+                let mut i = 0;
+                while i < Index::ALL_FIELDS.len() {
+                    pieces[c][p][i] = POLYGLOT_RANDOM[64 * kind + i];
+                    i += 1;
+                }
+                p += 1;
+            }
+
+            castling[c][Piece::King as usize] = POLYGLOT_RANDOM[768 + 2 * c];
+            castling[c][Piece::Queen as usize] = POLYGLOT_RANDOM[769 + 2 * c];
+            c += 1;
+        }
+
+        let mut i = 0;
+        while i < Index::ALL_FIELDS.len() {
+            en_passant[i] = POLYGLOT_RANDOM[772 + Index::new(i).file()];
+            i += 1;
+        }
+
+        Zobrist {
+            pieces,
+            castling,
+            // Polyglot has no IDs for Chess960 castling rooks
+            castling_rook_files: [[[0u64; 8]; 2]; Color::VALUES.len()],
+            en_passant,
+            side: POLYGLOT_RANDOM[780],
+            polyglot: true,
         }
     }
 
+    /// Returns unique ID of a piece on a square.
+    #[inline(always)]
+    pub(crate) const fn piece(&self, color: Color, piece: Piece, i: Index) -> u64 {
+        self.pieces[color as usize][piece as usize][i.index]
+    }
+
+    /// Returns unique ID of a castling option with a castling rook, piece is either King or Queen.
+    /// Castling with other than the corner rook adds ID of the rook file,
+    /// so Chess960 positions differing only in the castling rooks have different hashes.
+    #[inline(always)]
+    pub(crate) const fn castling(&self, color: Color, piece: Piece, rook: Index) -> u64 {
+        let mut id = self.castling[color as usize][piece as usize];
+        if rook.index != ChessBoard::STANDARD_CASTLING_ROOKS[color as usize][piece as usize].index {
+            id ^= self.castling_rook_files[color as usize][piece as usize][rook.file()];
+        }
+        id
+    }
+
+    /// Returns unique ID of an en-passant target.
+    #[inline(always)]
+    pub(crate) const fn en_passant(&self, i: Index) -> u64 {
+        self.en_passant[i.index]
+    }
+
+    /// Returns unique ID of black side on move.
+    #[inline(always)]
+    pub(crate) const fn side(&self) -> u64 {
+        self.side
+    }
+
     /// Creates Zobrist hash of a chess board.
     ///
     /// # Examples
@@ -81,37 +252,76 @@ impl Zobrist {
     ///       a b c d e f g h
     /// ").unwrap();
     ///
-    ///  let hash = Zobrist::new().hash(&board);
+    /// let hash = Zobrist::new().hash(&board);
+    ///
+    /// assert_eq!(hash, board.hash());
     /// ```
-    pub fn hash(&self, board: &ChessBoard) -> u64 {
+    pub const fn hash(&self, board: &ChessBoard) -> u64 {
+        // note: loops are written in a const fn compatible way
         let mut hash = 0u64;
 
-        if board.next_move != Color::White {
+        if matches!(board.next_move, Color::Black) != self.polyglot {
             hash ^= self.side;
         }
 
-        for c in Color::VALUES {
-            for p in [Piece::King, Piece::Queen] {
-                if board.castling_options[*c][*p] {
-                    hash ^= self.castling[*c][*p];
-                }
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let color = Color::VALUES[c];
+            if let Some(rook) = board.castling_rook(color, Piece::King) {
+                hash ^= self.castling(color, Piece::King, rook);
+            }
+            if let Some(rook) = board.castling_rook(color, Piece::Queen) {
+                hash ^= self.castling(color, Piece::Queen, rook);
             }
+            c += 1;
         }
 
-        if let Some(en_passant_target) = board.en_passant_target {
-            hash ^= self.en_passant[*en_passant_target];
+        if let Some(en_passant_target) = board.en_passant_target
+            && (!self.polyglot || Zobrist::is_en_passant_capturable(board, en_passant_target))
+        {
+            hash ^= self.en_passant[en_passant_target.index];
         }
 
-        for c in 0..Color::VALUES.len() {
-            for p in 0..Piece::VALUES.len() {
+        let mut c = 0;
+        while c < Color::VALUES.len() {
+            let mut p = 0;
+            while p < Piece::VALUES.len() {
                 let mut pieces = board.pieces[c][p];
                 while let (Some(i), tmp) = pieces.bitpop() {
                     pieces = tmp;
-                    hash ^= self.pieces[c][p][*i];
+                    hash ^= self.pieces[c][p][i.index];
                 }
+                p += 1;
             }
+            c += 1;
         }
 
         hash
     }
+
+    /// Checks if there is a pawn of side on move next to the pawn which made the double push.
+    const fn is_en_passant_capturable(board: &ChessBoard, en_passant_target: Index) -> bool {
+        let target = en_passant_target.as_bitboard();
+        let attackers = match board.next_move {
+            Color::White => {
+                BitBoard::new(target.shifted_southeast().state | target.shifted_southwest().state)
+            }
+            Color::Black => {
+                BitBoard::new(target.shifted_northeast().state | target.shifted_northwest().state)
+            }
+        };
+
+        attackers.state & board.pieces[board.next_move as usize][Piece::Pawn as usize].state != 0
+    }
+}
+
+/// SplitMix64 pseudo random generator, usable in const context.
+///
+/// See: [SplitMix64](https://prng.di.unimi.it/splitmix64.c)
+const fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
 }
@@ -1,20 +1,47 @@
+pub use self::builder::ChessBoardBuilder;
 pub use self::chessboard::ChessBoard;
 pub use self::color::Color;
+pub use self::error::InvalidChessBoardError;
 pub use self::error::InvalidChessBoardStringError;
 pub use self::error::InvalidColorNotationError;
 pub use self::error::InvalidFENStringError;
 pub use self::error::InvalidMoveNotationError;
+pub use self::error::InvalidPackedChessBoardError;
 pub use self::error::InvalidPieceNotationError;
+pub use self::fen_field::FenField;
+pub use self::game::Game;
 pub use self::generator::Generator;
 pub use self::generator::IllegalMoveError;
 pub use self::generator::PerfT;
+pub use self::generator::PerfTStats;
 pub use self::generator::Zobrist;
 pub use self::r#move::Move;
+pub use self::move_generation::MoveGeneration;
+pub use self::move_kind::MoveKind;
+pub use self::move_list::MoveList;
+pub use self::outcome::Outcome;
 pub use self::piece::Piece;
+pub use self::piece_values::PieceValues;
+pub use self::undo_info::UndoInfo;
+mod builder;
+mod chess960;
 #[allow(clippy::module_inception)]
 mod chessboard;
 mod color;
 mod error;
+mod fen_field;
+mod game;
 mod generator;
 mod r#move;
+mod move_generation;
+mod move_kind;
+mod move_list;
+mod outcome;
+mod packed;
 mod piece;
+mod piece_values;
+mod san;
+mod see;
+mod transform;
+mod undo_info;
+mod validation;
@@ -2,10 +2,40 @@ use std::fmt::Display;
 
 use crate::bitboard::Index;
 
-use super::{InvalidMoveNotationError, Piece};
+use super::{InvalidMoveNotationError, MoveKind, Piece};
 
 /// Chess Move definition.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///
+/// Moves produced by the moves generator carry also the moving piece,
+/// the captured piece and the kind of the move.
+/// Moves parsed from UCI notation or constructed by [Move::new()] have no such details,
+/// [crate::ChessBoard::resolve_move()] finds the details for them.
+///
+/// Moves are compared only by `from`, `to` and `promotion`,
+/// so generated moves are equal to the same moves in UCI notation.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, Move, MoveKind, Piece};
+///
+/// let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K2R w K d6 0 1").unwrap();
+/// let moves = board.legal_moves();
+///
+/// let m = moves.iter().find(|m| **m == Move::from_string("e5d6").unwrap()).unwrap();
+/// assert_eq!(m.piece, Some(Piece::Pawn));
+/// assert_eq!(m.captured, Some(Piece::Pawn));
+/// assert_eq!(m.kind, MoveKind::EnPassant);
+/// assert!(m.is_capture());
+///
+/// let m = moves.iter().find(|m| **m == Move::from_string("e1g1").unwrap()).unwrap();
+/// assert_eq!(m.piece, Some(Piece::King));
+/// assert_eq!(m.captured, None);
+/// assert!(m.is_castling());
+///
+/// assert_eq!(Move::from_string("e1g1").unwrap().piece, None);
+/// ```
+#[derive(Debug, Copy, Clone)]
 pub struct Move {
     /// Index from which the piece moved.
     pub from: Index,
@@ -13,10 +43,42 @@ pub struct Move {
     pub to: Index,
     /// Promotion piece
     pub promotion: Option<Piece>,
+    /// Moving piece, if known.
+    pub piece: Option<Piece>,
+    /// Captured piece, pawn for en-passant captures.
+    /// Valid only if the moving piece is known.
+    pub captured: Option<Piece>,
+    /// Kind of the move.
+    /// Valid only if the moving piece is known.
+    pub kind: MoveKind,
 }
 
+/// Moves are equal if they have the same from, to and promotion.
+impl PartialEq for Move {
+    fn eq(&self, other: &Self) -> bool {
+        self.from == other.from && self.to == other.to && self.promotion == other.promotion
+    }
+}
+
+impl Eq for Move {}
+
 impl Move {
-    /// Constructs a new Move.
+    /// Null move, passing the turn to the opponent.
+    /// Its UCI notation is `0000`, see [crate::ChessBoard::apply_null_move()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Move;
+    ///
+    /// assert_eq!(Move::NULL.to_string(), "0000");
+    /// assert_eq!(Move::from_string("0000").unwrap(), Move::NULL);
+    /// assert!(Move::NULL.is_null());
+    /// assert!(!Move::from_string("a1a2").unwrap().is_null());
+    /// ```
+    pub const NULL: Move = Move::new(Index::A1, Index::A1, None);
+
+    /// Constructs a new Move without details of the moving piece.
     #[inline(always)]
     #[must_use]
     pub const fn new(from: Index, to: Index, promotion: Option<Piece>) -> Self {
@@ -24,6 +86,138 @@ impl Move {
             from,
             to,
             promotion,
+            piece: None,
+            captured: None,
+            kind: MoveKind::Normal,
+        }
+    }
+
+    /// Checks if the move captures a piece, including en-passant captures.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_capture(&self) -> bool {
+        self.captured.is_some()
+    }
+
+    /// Checks if the move is castling.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_castling(&self) -> bool {
+        self.kind == MoveKind::Castling
+    }
+
+    /// Checks if the move is en-passant capture.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_en_passant(&self) -> bool {
+        self.kind == MoveKind::EnPassant
+    }
+
+    /// Checks if the move is a double pawn push.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_double_pawn_push(&self) -> bool {
+        self.kind == MoveKind::DoublePawnPush
+    }
+
+    /// Checks if the move is [Move::NULL].
+    #[inline(always)]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        *self == Move::NULL
+    }
+
+    /// Encodes the move into 16 bits.
+    /// Bits 0-5 hold `from`, bits 6-11 hold `to` and bits 12-15 hold the flags:
+    ///
+    /// | flags | move                  |
+    /// |-------|-----------------------|
+    /// | 0     | quiet move            |
+    /// | 1     | double pawn push      |
+    /// | 2     | king side castling    |
+    /// | 3     | queen side castling   |
+    /// | 4     | capture               |
+    /// | 5     | en-passant capture    |
+    /// | 8-11  | promotion to N, B, R, Q |
+    /// | 12-15 | capture with promotion to N, B, R, Q |
+    ///
+    /// Moves without details of the moving piece are encoded as quiet moves or promotions.
+    /// [Move::NULL] is encoded as 0.
+    ///
+    /// See: [ChessProgramming Encoding Moves](https://www.chessprogramming.org/Encoding_Moves)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -").unwrap();
+    ///
+    /// for m in board.legal_moves() {
+    ///     let decoded = Move::from_u16(m.to_u16());
+    ///     assert_eq!(decoded, m);
+    ///     assert_eq!(decoded.kind, m.kind);
+    ///     assert_eq!(board.resolve_move(&decoded).unwrap().to_u16(), m.to_u16());
+    /// }
+    ///
+    /// assert_eq!(Move::NULL.to_u16(), 0);
+    /// assert_eq!(Move::from_string("e1g1").unwrap().to_u16(), 0x0184);
+    /// assert_eq!(board.resolve_move(&Move::from_string("e1g1").unwrap()).unwrap().to_u16(), 0x2184);
+    /// ```
+    #[must_use]
+    pub fn to_u16(&self) -> u16 {
+        let flags = match (self.promotion, self.kind) {
+            (Some(promotion), _) => {
+                let promotion = match promotion {
+                    Piece::Knight => 0,
+                    Piece::Bishop => 1,
+                    Piece::Rook => 2,
+                    _ => 3,
+                };
+                let capture = if self.is_capture() { 4 } else { 0 };
+                8 | capture | promotion
+            }
+            (None, MoveKind::DoublePawnPush) => 1,
+            (None, MoveKind::Castling) if self.to.file() > self.from.file() => 2,
+            (None, MoveKind::Castling) => 3,
+            (None, MoveKind::EnPassant) => 5,
+            (None, MoveKind::Normal) if self.is_capture() => 4,
+            (None, MoveKind::Normal) => 0,
+        };
+
+        (self.from.index | self.to.index << 6 | flags << 12) as u16
+    }
+
+    /// Decodes a move encoded by [Move::to_u16()].
+    /// Only the kind of the move is restored, the moving and the captured pieces are not encoded.
+    /// [crate::ChessBoard::resolve_move()] finds the details of the move.
+    #[must_use]
+    pub fn from_u16(m: u16) -> Self {
+        let m = m as usize;
+        let flags = m >> 12;
+
+        let promotion = match flags {
+            8 | 12 => Some(Piece::Knight),
+            9 | 13 => Some(Piece::Bishop),
+            10 | 14 => Some(Piece::Rook),
+            11 | 15 => Some(Piece::Queen),
+            _ => None,
+        };
+
+        let kind = match flags {
+            1 => MoveKind::DoublePawnPush,
+            2 | 3 => MoveKind::Castling,
+            5 => MoveKind::EnPassant,
+            _ => MoveKind::Normal,
+        };
+
+        // captured piece is not encoded, it is known only for en-passant
+        let captured = if flags == 5 { Some(Piece::Pawn) } else { None };
+
+        Move {
+            captured,
+            kind,
+            ..Move::new(Index::new(m & 63), Index::new((m >> 6) & 63), promotion)
         }
     }
 
@@ -44,6 +238,10 @@ impl Move {
     /// assert!(Move::from_string("a7a8X").is_err());
     /// ```
     pub fn from_string(s: &str) -> Result<Self, InvalidMoveNotationError> {
+        if s == "0000" {
+            return Ok(Move::NULL);
+        }
+
         if !(4..=5).contains(&s.len()) {
             return Err(InvalidMoveNotationError::InvalidString(s.to_string()));
         }
@@ -80,6 +278,10 @@ impl Move {
 /// ```
 impl Display for Move {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_null() {
+            return write!(f, "0000");
+        }
+
         write!(f, "{}{}", self.from, self.to)?;
         if let Some(promotion) = self.promotion {
             write!(f, "{}", promotion)?;
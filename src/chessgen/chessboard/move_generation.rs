@@ -0,0 +1,18 @@
+/// Subset of legal moves to generate, see [crate::ChessBoard::legal_moves_of()].
+///
+/// [MoveGeneration::Captures] and [MoveGeneration::Quiets] split all legal moves
+/// into two disjoint sets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum MoveGeneration {
+    /// All legal moves.
+    #[default]
+    All,
+    /// Captures, including en-passant, and promotions.
+    Captures,
+    /// Moves which are neither captures nor promotions, including castling.
+    Quiets,
+    /// Moves escaping a check, nothing if the side to move is not under check.
+    Evasions,
+    /// Quiet moves giving check, directly or by discovering an attack.
+    QuietChecks,
+}
@@ -0,0 +1,13 @@
+/// Kind of a chess move.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum MoveKind {
+    /// Quiet move, capture or promotion.
+    #[default]
+    Normal,
+    /// Pawn moving two fields forward.
+    DoublePawnPush,
+    /// Pawn capturing en-passant.
+    EnPassant,
+    /// King castling.
+    Castling,
+}
@@ -0,0 +1,178 @@
+use std::ops::{Deref, DerefMut};
+
+use super::Move;
+
+/// Fixed capacity list of moves allocated on the stack.
+/// Dereferences to a slice of moves, so it may be indexed, iterated and sorted in place.
+///
+/// Capacity is big enough for moves of any legal chess position (the maximum known is 218),
+/// pushing more moves than [MoveList::CAPACITY] panics.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, Move, MoveList};
+///
+/// let mut moves = ChessBoard::STANDARD.legal_moves();
+///
+/// assert_eq!(moves.len(), 20);
+/// assert!(moves.contains(&Move::from_string("e2e4").unwrap()));
+///
+/// moves.sort_unstable_by_key(|m| m.to_string());
+/// assert_eq!(moves[0], Move::from_string("a2a3").unwrap());
+/// assert_eq!(moves.iter().filter(|m| m.to.rank() == 3).count(), 8);
+///
+/// let mut list = MoveList::new();
+/// list.push(Move::from_string("e2e4").unwrap());
+/// assert_eq!(list.len(), 1);
+///
+/// for m in list {
+///     assert_eq!(m.to_string(), "e2e4");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoveList {
+    /// Storage of the moves.
+    moves: [Move; MoveList::CAPACITY],
+    /// Number of the moves in the list.
+    len: usize,
+}
+
+/// Constructs a new empty MoveList.
+impl Default for MoveList {
+    fn default() -> Self {
+        MoveList::new()
+    }
+}
+
+impl MoveList {
+    /// Maximal number of moves in the list.
+    pub const CAPACITY: usize = 256;
+
+    /// Constructs a new empty list.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        MoveList {
+            moves: [Move::NULL; MoveList::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Appends a move to the end of the list.
+    #[inline(always)]
+    pub fn push(&mut self, m: Move) {
+        self.moves[self.len] = m;
+        self.len += 1;
+    }
+
+    /// Removes all moves from the list.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the moves as a slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &[Move] {
+        &self.moves[..self.len]
+    }
+
+    /// Returns the moves as a mutable slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [Move] {
+        &mut self.moves[..self.len]
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Move];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for MoveList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+/// Lists are equal if they have the same moves in the same order.
+impl PartialEq for MoveList {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for MoveList {}
+
+impl Extend<Move> for MoveList {
+    fn extend<T: IntoIterator<Item = Move>>(&mut self, iter: T) {
+        for m in iter {
+            self.push(m);
+        }
+    }
+}
+
+impl FromIterator<Move> for MoveList {
+    fn from_iter<T: IntoIterator<Item = Move>>(iter: T) -> Self {
+        let mut list = MoveList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveList {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for MoveList {
+    type Item = Move;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            list: self,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over moves of a [MoveList].
+#[derive(Debug, Clone)]
+pub struct IntoIter {
+    /// Iterated list.
+    list: MoveList,
+    /// Index of the next move.
+    index: usize,
+}
+
+impl Iterator for IntoIter {
+    type Item = Move;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.list.len {
+            self.index += 1;
+            Some(self.list.moves[self.index - 1])
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.list.len - self.index;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
@@ -0,0 +1,55 @@
+use super::Color;
+
+/// Outcome of a game, see [crate::ChessBoard::outcome()] and [crate::Game::outcome()].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    /// Side to move is checkmated, contains the winning color.
+    Checkmate(Color),
+    /// Side to move has no legal move and is not under check.
+    Stalemate,
+    /// Neither side has enough material to checkmate.
+    InsufficientMaterial,
+    /// No capture or pawn move in the last 75 moves, the game is drawn.
+    SeventyFiveMoveRule,
+    /// No capture or pawn move in the last 50 moves, a draw may be claimed.
+    FiftyMoveRule,
+    /// Same position occurred five times, the game is drawn.
+    FivefoldRepetition,
+    /// Same position occurred three times, a draw may be claimed.
+    ThreefoldRepetition,
+}
+
+impl Outcome {
+    /// Returns color of the winner or None for a draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Color, Outcome};
+    ///
+    /// assert_eq!(Outcome::Checkmate(Color::Black).winner(), Some(Color::Black));
+    /// assert_eq!(Outcome::Stalemate.winner(), None);
+    /// ```
+    #[must_use]
+    pub const fn winner(self) -> Option<Color> {
+        match self {
+            Outcome::Checkmate(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// Checks if the outcome is a draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{Color, Outcome};
+    ///
+    /// assert!(Outcome::InsufficientMaterial.is_draw());
+    /// assert!(!Outcome::Checkmate(Color::White).is_draw());
+    /// ```
+    #[must_use]
+    pub const fn is_draw(self) -> bool {
+        self.winner().is_none()
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{BitBoard, Index};
+
+use super::{ChessBoard, Color, InvalidPackedChessBoardError, Piece};
+
+/// Maximal number of pieces of a packed board.
+const MAX_PIECES: usize = 32;
+
+/// En-Passant byte of a packed board without en-passant target.
+const NO_EN_PASSANT: u8 = 0xFF;
+
+/// Order of castling options bits of a packed board.
+const CASTLING_BITS: [(Color, Piece); 4] = [
+    (Color::White, Piece::King),
+    (Color::White, Piece::Queen),
+    (Color::Black, Piece::King),
+    (Color::Black, Piece::Queen),
+];
+
+/// Compact binary representation of a ChessBoard.
+///
+/// Layout of [ChessBoard::PACKED_SIZE] bytes, numbers are little-endian:
+///
+/// | bytes  | content                                                         |
+/// |--------|-----------------------------------------------------------------|
+/// | 0..8   | occupancy bitboard                                              |
+/// | 8..24  | pieces in occupancy order, one nibble each, low nibble first    |
+/// | 24     | bit 0: Black to move, bits 1-4: castling options `KQkq`, bit 5: Chess960 |
+/// | 25     | en-passant target index, `0xFF` if there is none                |
+/// | 26..28 | half move clock                                                 |
+/// | 28..32 | full move number                                                |
+///
+/// Piece nibble is `color * 6 + piece`, see [Color] and [Piece] values.
+/// Castling options refer to the outermost rooks on the side of the king, as in X-FEN.
+impl ChessBoard {
+    /// Size of a packed board in bytes.
+    pub const PACKED_SIZE: usize = 32;
+
+    /// Returns compact binary representation of the board.
+    /// Boards with more than 32 pieces, with move clocks out of range
+    /// or with Chess960 castling options of inner rooks can not be packed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// for fen in [
+    ///     ChessBoard::STANDARD_BOARD_FEN,
+    ///     "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    ///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+    ///     "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 37 112",
+    ///     "8/8/8/8/8/8/8/8 b - - 0 1",
+    ///     "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+    /// ] {
+    ///     let board = ChessBoard::from_fen(fen).unwrap();
+    ///     let packed = board.to_packed().unwrap();
+    ///
+    ///     assert_eq!(packed.len(), ChessBoard::PACKED_SIZE);
+    ///     assert_eq!(ChessBoard::from_packed(&packed).unwrap(), board);
+    ///     assert_eq!(ChessBoard::from_packed(&packed).unwrap().to_fen(), fen);
+    /// }
+    ///
+    /// let board = ChessBoard::from_fen("qqqqkqqq/qqqqqqqq/8/8/8/Q7/QQQQQQQQ/QQQQKQQQ w - - 0 1").unwrap();
+    /// assert!(board.to_packed().is_err());
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/RR2K3 w B - 0 1").unwrap();
+    /// assert!(board.to_packed().is_err());
+    /// ```
+    pub fn to_packed(&self) -> Result<[u8; ChessBoard::PACKED_SIZE], InvalidPackedChessBoardError> {
+        let occupancy = self.all_pieces();
+        if occupancy.popcnt() > MAX_PIECES {
+            return Err(InvalidPackedChessBoardError::TooManyPieces(
+                occupancy.popcnt(),
+            ));
+        }
+
+        let Ok(half_move_clock) = u16::try_from(self.half_move_clock) else {
+            return Err(InvalidPackedChessBoardError::ClockOverflow(
+                self.half_move_clock,
+            ));
+        };
+        let Ok(full_move_number) = u32::try_from(self.full_move_number) else {
+            return Err(InvalidPackedChessBoardError::ClockOverflow(
+                self.full_move_number,
+            ));
+        };
+
+        let castling = self.castling_to_fen(false);
+        if castling.chars().any(|c| !"KQkq-".contains(c)) {
+            return Err(InvalidPackedChessBoardError::UnsupportedCastling(castling));
+        }
+
+        let mut packed = [0u8; ChessBoard::PACKED_SIZE];
+        packed[0..8].copy_from_slice(&occupancy.state.to_le_bytes());
+
+        let mut b = occupancy;
+        let mut n = 0;
+        while let (Some(i), tmp) = b.bitpop() {
+            b = tmp;
+            let (color, piece) = self.piece_at(i).unwrap();
+            let code = (*color * Piece::VALUES.len() + *piece) as u8;
+            packed[8 + n / 2] |= code << (4 * (n % 2));
+            n += 1;
+        }
+
+        let mut flags = 0u8;
+        if self.next_move == Color::Black {
+            flags |= 1;
+        }
+        for (bit, (color, piece)) in CASTLING_BITS.iter().enumerate() {
+            if self.castling_options[**color][**piece] {
+                flags |= 2 << bit;
+            }
+        }
+        if self.is_chess960() {
+            flags |= 1 << 5;
+        }
+        packed[24] = flags;
+
+        packed[25] = match self.en_passant_target {
+            Some(i) => i.index as u8,
+            None => NO_EN_PASSANT,
+        };
+
+        packed[26..28].copy_from_slice(&half_move_clock.to_le_bytes());
+        packed[28..32].copy_from_slice(&full_move_number.to_le_bytes());
+
+        Ok(packed)
+    }
+
+    /// Returns ChessBoard from its binary representation made by [ChessBoard::to_packed()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let packed = ChessBoard::STANDARD.to_packed().unwrap();
+    /// assert_eq!(ChessBoard::from_packed(&packed).unwrap(), ChessBoard::STANDARD);
+    ///
+    /// assert!(ChessBoard::from_packed(&packed[1..]).is_err());
+    ///
+    /// let mut invalid = packed;
+    /// invalid[8] = 0xFF;
+    /// assert!(ChessBoard::from_packed(&invalid).is_err());
+    /// ```
+    pub fn from_packed(packed: &[u8]) -> Result<Self, InvalidPackedChessBoardError> {
+        if packed.len() != ChessBoard::PACKED_SIZE {
+            return Err(InvalidPackedChessBoardError::InvalidLength(packed.len()));
+        }
+
+        let occupancy = BitBoard::new(u64::from_le_bytes(packed[0..8].try_into().unwrap()));
+        if occupancy.popcnt() > MAX_PIECES {
+            return Err(InvalidPackedChessBoardError::TooManyPieces(
+                occupancy.popcnt(),
+            ));
+        }
+
+        let mut pieces = [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()];
+
+        let mut b = occupancy;
+        let mut n = 0;
+        while let (Some(i), tmp) = b.bitpop() {
+            b = tmp;
+            let nibble = (packed[8 + n / 2] >> (4 * (n % 2))) & 0x0F;
+            let code = nibble as usize;
+            if code >= Color::VALUES.len() * Piece::VALUES.len() {
+                return Err(InvalidPackedChessBoardError::InvalidPiece(nibble));
+            }
+            let color = Color::VALUES[code / Piece::VALUES.len()];
+            let piece = Piece::VALUES[code % Piece::VALUES.len()];
+            pieces[*color][*piece] |= i;
+            n += 1;
+        }
+
+        let flags = packed[24];
+        let next_move = if flags & 1 != 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+
+        let mut castling = String::new();
+        for (bit, (color, piece)) in CASTLING_BITS.iter().enumerate() {
+            if flags & (2 << bit) != 0 {
+                castling.push(piece.to_char(*color));
+            }
+        }
+        let (castling_options, castling_rooks, _) =
+            ChessBoard::castling_from_fen(&pieces, &castling).unwrap();
+        let chess960 = flags & (1 << 5) != 0;
+
+        let en_passant_target = match packed[25] {
+            NO_EN_PASSANT => None,
+            i if (i as usize) < Index::ALL_FIELDS.len() => Some(Index::new(i as usize)),
+            i => return Err(InvalidPackedChessBoardError::InvalidEnPassant(i)),
+        };
+
+        let half_move_clock = u16::from_le_bytes(packed[26..28].try_into().unwrap());
+        let full_move_number = u32::from_le_bytes(packed[28..32].try_into().unwrap());
+
+        Ok(ChessBoard::from_parts(
+            pieces,
+            next_move,
+            castling_options,
+            castling_rooks,
+            chess960,
+            en_passant_target,
+            half_move_clock as usize,
+            full_move_number as usize,
+        ))
+    }
+}
@@ -0,0 +1,59 @@
+use super::Piece;
+
+/// Values of pieces in centipawns, used by [crate::ChessBoard::see_with()].
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{Piece, PieceValues};
+///
+/// assert_eq!(PieceValues::default().value(Piece::Pawn), 100);
+///
+/// let values = PieceValues::new(325, 325, 500, 975);
+/// assert_eq!(values.value(Piece::Knight), 325);
+/// assert_eq!(values.value(Piece::King), PieceValues::DEFAULT.value(Piece::King));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PieceValues {
+    /// Values of pieces: `[piece]`.
+    pub values: [i32; Piece::VALUES.len()],
+}
+
+/// Constructs [PieceValues::DEFAULT].
+impl Default for PieceValues {
+    fn default() -> Self {
+        PieceValues::DEFAULT
+    }
+}
+
+impl PieceValues {
+    /// Value of the king, bigger than value of all other pieces together.
+    pub const KING: i32 = 20_000;
+
+    /// Value of the pawn.
+    pub const PAWN: i32 = 100;
+
+    /// Classical piece values: pawn 100, knight and bishop 300, rook 500 and queen 900.
+    pub const DEFAULT: PieceValues = PieceValues::new(300, 300, 500, 900);
+
+    /// Constructs piece values relative to the pawn value of 100.
+    #[must_use]
+    pub const fn new(knight: i32, bishop: i32, rook: i32, queen: i32) -> Self {
+        let mut values = [0; Piece::VALUES.len()];
+        values[Piece::King as usize] = PieceValues::KING;
+        values[Piece::Queen as usize] = queen;
+        values[Piece::Bishop as usize] = bishop;
+        values[Piece::Knight as usize] = knight;
+        values[Piece::Rook as usize] = rook;
+        values[Piece::Pawn as usize] = PieceValues::PAWN;
+
+        PieceValues { values }
+    }
+
+    /// Returns value of a piece.
+    #[inline(always)]
+    #[must_use]
+    pub const fn value(&self, piece: Piece) -> i32 {
+        self.values[piece as usize]
+    }
+}
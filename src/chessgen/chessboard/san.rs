@@ -0,0 +1,292 @@
+use crate::Index;
+
+use super::{ChessBoard, Color, InvalidMoveNotationError, Move, Piece};
+
+/// Standard Algebraic Notation.
+///
+/// See: [ChessProgramming Algebraic Chess Notation](https://www.chessprogramming.org/Algebraic_Chess_Notation)
+impl ChessBoard {
+    /// Returns SAN representation of a legal move.
+    /// Results for illegal moves are unpredictable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.move_to_san(&m("e2e4")), "e4");
+    /// assert_eq!(ChessBoard::STANDARD.move_to_san(&m("g1f3")), "Nf3");
+    ///
+    /// // file disambiguation
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("b1d2")), "Nbd2");
+    /// assert_eq!(board.move_to_san(&m("f3d2")), "Nfd2");
+    ///
+    /// // rank disambiguation
+    /// let board = ChessBoard::from_fen("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("a1a3")), "R1a3");
+    /// assert_eq!(board.move_to_san(&m("a5a3")), "R5a3");
+    ///
+    /// // square disambiguation
+    /// let board = ChessBoard::from_fen("8/7k/8/8/8/Q7/8/Q1Q4K w - - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("a1b2")), "Qa1b2");
+    /// assert_eq!(board.move_to_san(&m("a3b2")), "Q3b2");
+    /// assert_eq!(board.move_to_san(&m("c1b2")), "Qcb2");
+    ///
+    /// // castling
+    /// let board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("e1g1")), "O-O");
+    /// assert_eq!(board.move_to_san(&m("e1c1")), "O-O-O");
+    ///
+    /// // Chess960 castling, king captures own rook
+    /// let board = ChessBoard::from_fen("1r2k1r1/8/8/8/8/8/8/1R2K1R1 w GBgb - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("e1g1")), "O-O");
+    /// assert_eq!(board.move_to_san(&m("e1b1")), "O-O-O");
+    /// assert_eq!(board.parse_san("O-O-O").unwrap(), m("e1b1"));
+    ///
+    /// // captures, en-passant
+    /// let board = ChessBoard::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    /// assert_eq!(board.move_to_san(&m("e5f6")), "exf6");
+    /// assert_eq!(board.parse_san("exf6 e.p.").unwrap(), m("e5f6"));
+    /// assert_eq!(board.move_to_san(&m("f1b5")), "Bb5+");
+    ///
+    /// // promotion with check
+    /// let board = ChessBoard::from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    /// assert_eq!(board.move_to_san(&m("a7a8q")), "a8=Q+");
+    /// assert_eq!(board.move_to_san(&m("a7a8n")), "a8=N");
+    ///
+    /// // checkmate
+    /// let board = ChessBoard::from_fen("rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq g3 0 2").unwrap();
+    /// assert_eq!(board.move_to_san(&m("d8h4")), "Qh4#");
+    /// ```
+    pub fn move_to_san(&self, m: &Move) -> String {
+        let mut san = String::new();
+
+        let Some((_, piece)) = self.piece_at(m.from) else {
+            return m.to_string();
+        };
+
+        if piece == Piece::King && self.is_castling(m) {
+            if ChessBoard::castling_side(m) == Piece::King {
+                san.push_str("O-O");
+            } else {
+                san.push_str("O-O-O");
+            }
+        } else {
+            let is_capture = self.pieces(self.next_move.opponent()).has_bit(m.to)
+                || (piece == Piece::Pawn && m.from.file() != m.to.file());
+
+            if piece == Piece::Pawn {
+                if is_capture {
+                    san.push(Self::file_char(m.from));
+                }
+            } else {
+                san.push(piece.to_char(Color::White));
+
+                // disambiguation
+                let mut ambiguous = false;
+                let mut same_file = false;
+                let mut same_rank = false;
+
+                for other in self.legal_moves() {
+                    if other.to == m.to
+                        && other.from != m.from
+                        && self.pieces[*self.next_move][*piece].has_bit(other.from)
+                    {
+                        ambiguous = true;
+                        same_file |= other.from.file() == m.from.file();
+                        same_rank |= other.from.rank() == m.from.rank();
+                    }
+                }
+
+                if ambiguous {
+                    if !same_file {
+                        san.push(Self::file_char(m.from));
+                    } else if !same_rank {
+                        san.push(Self::rank_char(m.from));
+                    } else {
+                        san.push_str(&m.from.to_string());
+                    }
+                }
+            }
+
+            if is_capture {
+                san.push('x');
+            }
+
+            san.push_str(&m.to.to_string());
+
+            if let Some(promotion) = m.promotion {
+                san.push('=');
+                san.push(promotion.to_char(Color::White));
+            }
+        }
+
+        let board = self.apply_move(m);
+        if board.is_my_king_under_check() {
+            if board.legal_moves().is_empty() {
+                san.push('#');
+            } else {
+                san.push('+');
+            }
+        }
+
+        san
+    }
+
+    /// Parses a move in SAN and validates it against the board.
+    ///
+    /// Parsing is tolerant to common variants: castling with zeros (`0-0`),
+    /// promotion without `=` (`e8Q`), missing or superfluous check and mate markers,
+    /// annotations (`!`, `?`), long algebraic notation (`Ng1-f3`, `e2e4`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.parse_san("e4").unwrap(), m("e2e4"));
+    /// assert_eq!(ChessBoard::STANDARD.parse_san("Nf3").unwrap(), m("g1f3"));
+    /// assert_eq!(ChessBoard::STANDARD.parse_san("Ng1-f3").unwrap(), m("g1f3"));
+    /// assert_eq!(ChessBoard::STANDARD.parse_san("e2e4").unwrap(), m("e2e4"));
+    /// assert!(ChessBoard::STANDARD.parse_san("e5").is_err());
+    /// assert!(ChessBoard::STANDARD.parse_san("Xe5").is_err());
+    /// assert!(ChessBoard::STANDARD.parse_san("").is_err());
+    ///
+    /// let board = ChessBoard::from_fen("r1bqkb1r/pppp1ppp/2n2n2/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4").unwrap();
+    /// assert_eq!(board.parse_san("O-O").unwrap(), m("e1g1"));
+    /// assert_eq!(board.parse_san("0-0").unwrap(), m("e1g1"));
+    /// assert_eq!(board.parse_san("Bxc6").unwrap(), m("b5c6"));
+    /// assert_eq!(board.parse_san("Bxc6!?").unwrap(), m("b5c6"));
+    ///
+    /// let board = ChessBoard::from_fen("r1bqkbnr/pppp1ppp/2n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3").unwrap();
+    /// assert_eq!(board.parse_san("Nge7").unwrap(), m("g8e7"));
+    /// assert!(board.parse_san("Nce7").is_ok());
+    /// assert!(board.parse_san("Ne7").is_err());
+    ///
+    /// let board = ChessBoard::from_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1").unwrap();
+    /// assert_eq!(board.parse_san("a8=Q+").unwrap(), m("a7a8q"));
+    /// assert_eq!(board.parse_san("a8Q").unwrap(), m("a7a8q"));
+    /// assert_eq!(board.parse_san("a8=N").unwrap(), m("a7a8n"));
+    /// assert!(board.parse_san("a8").is_err());
+    ///
+    /// let board = ChessBoard::from_fen("rn2k3/8/5n2/8/8/8/8/4K3 b - - 0 1").unwrap();
+    /// assert_eq!(board.parse_san("Nbd7+").unwrap(), m("b8d7"));
+    /// assert_eq!(board.parse_san("Nf6d7").unwrap(), m("f6d7"));
+    /// assert!(board.parse_san("Nd7").is_err());
+    /// ```
+    pub fn parse_san(&self, s: &str) -> Result<Move, InvalidMoveNotationError> {
+        let notation = s
+            .trim()
+            .trim_end_matches(['+', '#', '!', '?'])
+            .trim_end_matches("e.p.")
+            .trim_end()
+            .trim_end_matches(['+', '#'])
+            .replace(['x', ':', '-'], "");
+
+        if notation.is_empty() {
+            return Err(InvalidMoveNotationError::InvalidString(s.to_string()));
+        }
+
+        let legal_moves = self.legal_moves();
+        let my_pieces = &self.pieces[*self.next_move];
+
+        // castling
+        let castling = match notation.as_str() {
+            "OO" | "00" => Some(Piece::King),
+            "OOO" | "000" => Some(Piece::Queen),
+            _ => None,
+        };
+        if let Some(side) = castling {
+            return legal_moves
+                .iter()
+                .find(|m| m.is_castling() && ChessBoard::castling_side(m) == side)
+                .copied()
+                .ok_or_else(|| InvalidMoveNotationError::IllegalMove(s.to_string()));
+        }
+
+        let mut chars: Vec<char> = notation.chars().collect();
+
+        // moving piece
+        let piece = match chars[0] {
+            'K' => Piece::King,
+            'Q' => Piece::Queen,
+            'R' => Piece::Rook,
+            'B' => Piece::Bishop,
+            'N' => Piece::Knight,
+            'P' => Piece::Pawn,
+            'a'..='h' => Piece::Pawn,
+            _ => return Err(InvalidMoveNotationError::InvalidString(s.to_string())),
+        };
+        if chars[0].is_ascii_uppercase() {
+            chars.remove(0);
+        }
+
+        // promotion
+        let mut promotion = None;
+        if let Some(c) = chars.last().copied()
+            && piece == Piece::Pawn
+            && !c.is_ascii_digit()
+        {
+            promotion = match c.to_ascii_uppercase() {
+                'Q' => Some(Piece::Queen),
+                'R' => Some(Piece::Rook),
+                'B' => Some(Piece::Bishop),
+                'N' => Some(Piece::Knight),
+                _ => return Err(InvalidMoveNotationError::InvalidString(s.to_string())),
+            };
+            chars.pop();
+            if chars.last() == Some(&'=') {
+                chars.pop();
+            }
+        }
+
+        // target square
+        if chars.len() < 2 {
+            return Err(InvalidMoveNotationError::InvalidString(s.to_string()));
+        }
+        let target: String = chars.split_off(chars.len() - 2).into_iter().collect();
+        let Ok(to) = Index::from_string(&target) else {
+            return Err(InvalidMoveNotationError::InvalidString(s.to_string()));
+        };
+
+        // disambiguation
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in chars {
+            match c {
+                'a'..='h' if from_file.is_none() => from_file = Some(c as usize - 'a' as usize),
+                '1'..='8' if from_rank.is_none() => from_rank = Some(c as usize - '1' as usize),
+                _ => return Err(InvalidMoveNotationError::InvalidString(s.to_string())),
+            }
+        }
+
+        let mut candidates = legal_moves.iter().filter(|m| {
+            m.to == to
+                && m.promotion == promotion
+                && my_pieces[*piece].has_bit(m.from)
+                && from_file.is_none_or(|f| m.from.file() == f)
+                && from_rank.is_none_or(|r| m.from.rank() == r)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(m), None) => Ok(*m),
+            (None, _) => Err(InvalidMoveNotationError::IllegalMove(s.to_string())),
+            (Some(_), Some(_)) => Err(InvalidMoveNotationError::AmbiguousMove(s.to_string())),
+        }
+    }
+
+    /// Returns file of an index as a char.
+    fn file_char(i: Index) -> char {
+        (b'a' + i.file() as u8) as char
+    }
+
+    /// Returns rank of an index as a char.
+    fn rank_char(i: Index) -> char {
+        (b'1' + i.rank() as u8) as char
+    }
+}
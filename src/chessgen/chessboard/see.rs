@@ -0,0 +1,196 @@
+use crate::{BitBoard, Generator};
+
+use super::{ChessBoard, Color, Move, Piece, PieceValues};
+
+/// Order in which pieces recapture, from the least valuable one.
+const CAPTURE_ORDER: [Piece; 6] = [
+    Piece::Pawn,
+    Piece::Knight,
+    Piece::Bishop,
+    Piece::Rook,
+    Piece::Queen,
+    Piece::King,
+];
+
+/// Maximal length of a capture sequence, every piece may capture at most once.
+const MAX_CAPTURES: usize = 32;
+
+/// Static Exchange Evaluation.
+///
+/// See: [ChessProgramming Static Exchange Evaluation](https://www.chessprogramming.org/Static_Exchange_Evaluation)
+impl ChessBoard {
+    /// Returns material gain of a move after the best sequence of captures on its target field,
+    /// using [PieceValues::DEFAULT].
+    /// See [ChessBoard::see_with()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// // undefended pawn
+    /// let board = ChessBoard::from_fen("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("e1e5")), 100);
+    ///
+    /// // knight takes pawn, x-rays of the rook and of the queen behind the bishop decide
+    /// let board = ChessBoard::from_fen("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("d3e5")), -200);
+    /// ```
+    #[must_use]
+    pub fn see(&self, m: &Move) -> i32 {
+        self.see_with(m, &PieceValues::DEFAULT)
+    }
+
+    /// Checks if Static Exchange Evaluation of a move is at least a threshold,
+    /// using [PieceValues::DEFAULT].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move};
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// let board = ChessBoard::from_fen("4k3/8/3p4/4p3/3P4/8/8/4K3 w - - 0 1").unwrap();
+    /// assert!(board.see_ge(&m("d4e5"), 0));
+    /// assert!(!board.see_ge(&m("d4e5"), 1));
+    /// ```
+    #[must_use]
+    pub fn see_ge(&self, m: &Move, threshold: i32) -> bool {
+        self.see_ge_with(m, threshold, &PieceValues::DEFAULT)
+    }
+
+    /// Checks if Static Exchange Evaluation of a move using given piece values is at least a threshold.
+    #[must_use]
+    pub fn see_ge_with(&self, m: &Move, threshold: i32, values: &PieceValues) -> bool {
+        self.see_with(m, values) >= threshold
+    }
+
+    /// Returns material gain of a move after the best sequence of captures on its target field.
+    ///
+    /// Both sides capture with their least valuable piece and may stop capturing at any time.
+    /// Sliding pieces behind the capturing pieces join the sequence.
+    /// Pins are not considered, king captures only if the field is not defended anymore.
+    /// En-passant captures and promotions, also by recapturing pawns, are evaluated.
+    /// Quiet moves evaluate to zero or to the loss of the moved piece.
+    /// Castling always evaluates to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Move, PieceValues};
+    ///
+    /// let m = |s| Move::from_string(s).unwrap();
+    ///
+    /// let board = ChessBoard::from_fen("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see_with(&m("d3e5"), &PieceValues::new(325, 325, 500, 975)), -225);
+    ///
+    /// // en-passant
+    /// let board = ChessBoard::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+    /// assert_eq!(board.see(&m("e5d6")), 100);
+    ///
+    /// // promotions
+    /// let board = ChessBoard::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("a7b8q")), 1300);
+    /// assert_eq!(board.see(&m("a7a8q")), -100);
+    ///
+    /// // promotion, recaptured by the king
+    /// let board = ChessBoard::from_fen("3rk3/4P3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("e7d8q")), 400);
+    ///
+    /// // queen move to a field defended by a pawn
+    /// let board = ChessBoard::from_fen("4k3/8/4p3/8/8/8/8/3QK3 w - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("d1d5")), -900);
+    /// assert_eq!(board.see(&m("d1d4")), 0);
+    ///
+    /// // king does not capture a defended piece
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/1b6/3r4/3NK3 b - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("d2d1")), 300);
+    /// let board = ChessBoard::from_fen("4k3/8/8/8/8/8/3r4/3NK3 b - - 0 1").unwrap();
+    /// assert_eq!(board.see(&m("d2d1")), -200);
+    /// ```
+    #[must_use]
+    pub fn see_with(&self, m: &Move, values: &PieceValues) -> i32 {
+        let Some((color, piece)) = self.piece_at(m.from) else {
+            return 0;
+        };
+
+        let mut occupied = self.all_pieces() ^ m.from;
+
+        let captured = match self.piece_at(m.to) {
+            Some((c, _)) if c == color => return 0, // castling or an invalid move
+            Some((_, captured)) => values.value(captured),
+            None if piece == Piece::Pawn && self.en_passant_target == Some(m.to) => {
+                occupied ^= match color {
+                    Color::White => m.to.as_bitboard().shifted_south(),
+                    Color::Black => m.to.as_bitboard().shifted_north(),
+                };
+                values.value(Piece::Pawn)
+            }
+            None => 0,
+        };
+
+        let promotions = BitBoard::RANK_1 | BitBoard::RANK_8;
+
+        let mut gain = [0; MAX_CAPTURES];
+        gain[0] = captured;
+
+        // value of the piece standing on the field, to be captured next
+        let mut on_field = values.value(piece);
+        if let Some(promotion) = m.promotion {
+            gain[0] += values.value(promotion) - values.value(Piece::Pawn);
+            on_field = values.value(promotion);
+        }
+
+        let mut side = color.opponent();
+        let mut depth = 0;
+
+        while depth + 1 < MAX_CAPTURES {
+            let attackers =
+                Generator::G.attackers_to_occupied(self, m.to, side, occupied) & occupied;
+            if attackers == BitBoard::EMPTY {
+                break;
+            }
+
+            let (attacker, from) = CAPTURE_ORDER
+                .iter()
+                .find_map(|p| {
+                    (attackers & self.pieces[*side][**p])
+                        .bitscan()
+                        .map(|i| (*p, i))
+                })
+                .unwrap();
+
+            if attacker == Piece::King
+                && Generator::G.attackers_to_occupied(self, m.to, side.opponent(), occupied)
+                    & occupied
+                    != BitBoard::EMPTY
+            {
+                break;
+            }
+
+            // speculative gain, if the captured piece was not recaptured
+            depth += 1;
+            gain[depth] = on_field - gain[depth - 1];
+            on_field = values.value(attacker);
+
+            if attacker == Piece::Pawn && promotions.has_bit(m.to) {
+                gain[depth] += values.value(Piece::Queen) - values.value(Piece::Pawn);
+                on_field = values.value(Piece::Queen);
+            }
+
+            occupied ^= from;
+            side = side.opponent();
+        }
+
+        // each side may stop capturing if it would lose material
+        while depth > 0 {
+            gain[depth - 1] = -(-gain[depth - 1]).max(gain[depth]);
+            depth -= 1;
+        }
+
+        gain[0]
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{BitBoard, Index};
+
+use super::{ChessBoard, Color, Piece};
+
+/// Position transformations: color flip, horizontal mirror and canonical form.
+///
+/// Transformed positions are equivalent, evaluation from the point of view of the side to move
+/// and PerfT results do not change.
+///
+/// See: [ChessProgramming Color Flipping](https://www.chessprogramming.org/Color_Flipping)
+impl ChessBoard {
+    /// Returns the board mirrored vertically with swapped colors of the pieces,
+    /// side to move, castling options and en-passant target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, PerfT};
+    ///
+    /// let board = ChessBoard::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+    /// let flipped = board.flipped_colors();
+    ///
+    /// assert_eq!(flipped.to_fen(), "r3k2r/pppbbppp/2n2q1P/1P2p3/3pn3/BN2PNP1/P1PPQPB1/R3K2R b KQkq - 0 1");
+    /// assert_eq!(flipped.flipped_colors(), board);
+    ///
+    /// let perft = PerfT::new(1024 * 1024);
+    /// assert_eq!(perft.perft_n(&board, 3), perft.perft_n(&flipped, 3));
+    ///
+    /// let board = ChessBoard::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
+    /// assert_eq!(
+    ///     board.flipped_colors().to_fen(),
+    ///     "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn flipped_colors(&self) -> Self {
+        let mut pieces = [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()];
+        let mut castling_options = [[false; 2]; Color::VALUES.len()];
+        let mut castling_rooks = ChessBoard::STANDARD_CASTLING_ROOKS;
+
+        for c in Color::VALUES {
+            let opponent = c.opponent();
+
+            for p in Piece::VALUES {
+                pieces[*opponent][*p] = self.pieces[*c][*p].mirrored_vertically();
+            }
+
+            for side in [Piece::King, Piece::Queen] {
+                if let Some(rook) = self.castling_rook(c, side) {
+                    castling_options[*opponent][*side] = true;
+                    castling_rooks[*opponent][*side] = rook.mirrored_vertically();
+                }
+            }
+        }
+
+        ChessBoard::from_parts(
+            pieces,
+            self.next_move.opponent(),
+            castling_options,
+            castling_rooks,
+            self.is_chess960(),
+            self.en_passant_target.map(Index::mirrored_vertically),
+            self.half_move_clock,
+            self.full_move_number,
+        )
+    }
+
+    /// Returns the board mirrored horizontally, file A becomes file H.
+    /// Returns None if there is any castling option, castling is not symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, PerfT};
+    ///
+    /// let board = ChessBoard::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+    /// let mirrored = board.mirrored_horizontally().unwrap();
+    ///
+    /// assert_eq!(mirrored.to_fen(), "8/5p2/4p3/r5PK/k1p3R1/8/1P1P4/8 w - - 0 1");
+    /// assert_eq!(mirrored.mirrored_horizontally().unwrap(), board);
+    ///
+    /// let perft = PerfT::new(1024 * 1024);
+    /// assert_eq!(perft.perft_n(&board, 4), perft.perft_n(&mirrored, 4));
+    ///
+    /// assert!(ChessBoard::STANDARD.mirrored_horizontally().is_none());
+    /// ```
+    #[must_use]
+    pub fn mirrored_horizontally(&self) -> Option<Self> {
+        if self.castling_options.iter().flatten().any(|o| *o) {
+            return None;
+        }
+
+        let mut pieces = self.pieces;
+        for b in pieces.iter_mut().flatten() {
+            *b = b.mirrored_horizontally();
+        }
+
+        Some(ChessBoard::from_parts(
+            pieces,
+            self.next_move,
+            self.castling_options,
+            ChessBoard::STANDARD_CASTLING_ROOKS,
+            self.is_chess960(),
+            self.en_passant_target.map(Index::mirrored_horizontally),
+            self.half_move_clock,
+            self.full_move_number,
+        ))
+    }
+
+    /// Returns canonical form of the board, the same for all positions equivalent
+    /// by [ChessBoard::flipped_colors()] and [ChessBoard::mirrored_horizontally()].
+    /// Move clocks are kept, positions with different clocks have different canonical forms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::ChessBoard;
+    ///
+    /// let board = ChessBoard::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+    /// let canonical = board.canonical();
+    ///
+    /// assert_eq!(canonical.canonical(), canonical);
+    /// assert_eq!(board.flipped_colors().canonical(), canonical);
+    /// assert_eq!(board.mirrored_horizontally().unwrap().canonical(), canonical);
+    /// assert_eq!(board.flipped_colors().mirrored_horizontally().unwrap().canonical(), canonical);
+    ///
+    /// assert_eq!(ChessBoard::STANDARD.flipped_colors().canonical(), ChessBoard::STANDARD.canonical());
+    /// assert_ne!(ChessBoard::STANDARD.canonical(), canonical);
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        let flipped = self.flipped_colors();
+
+        [
+            Some(*self),
+            Some(flipped),
+            self.mirrored_horizontally(),
+            flipped.mirrored_horizontally(),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by_key(ChessBoard::canonical_key)
+        .unwrap()
+    }
+
+    /// Returns key ordering the equivalent boards, see [ChessBoard::canonical()].
+    #[allow(clippy::type_complexity)]
+    fn canonical_key(
+        &self,
+    ) -> (
+        [[u64; Piece::VALUES.len()]; Color::VALUES.len()],
+        usize,
+        Option<usize>,
+    ) {
+        (
+            self.pieces.map(|pieces| pieces.map(|b| b.state)),
+            *self.next_move,
+            self.en_passant_target.map(|i| *i),
+        )
+    }
+}
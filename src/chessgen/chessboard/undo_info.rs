@@ -0,0 +1,30 @@
+use crate::Index;
+
+use super::{Color, Piece};
+
+/// State of the board lost by [crate::ChessBoard::make_move()],
+/// needed by [crate::ChessBoard::unmake_move()] to restore the board.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UndoInfo {
+    /// Captured piece, pawn for en-passant captures.
+    pub(super) captured: Option<Piece>,
+    /// Castling options before the move.
+    pub(super) castling_options: [[bool; 2]; Color::VALUES.len()],
+    /// En-Passant target before the move.
+    pub(super) en_passant_target: Option<Index>,
+    /// Half move clock before the move.
+    pub(super) half_move_clock: usize,
+    /// Full move number before the move.
+    pub(super) full_move_number: usize,
+    /// Zobrist hash before the move.
+    pub(super) hash: u64,
+    /// The move was castling.
+    pub(super) castling: bool,
+}
+
+impl UndoInfo {
+    /// Returns the piece captured by the move, if any.
+    pub fn captured(&self) -> Option<Piece> {
+        self.captured
+    }
+}
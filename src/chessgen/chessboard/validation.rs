@@ -0,0 +1,330 @@
+use crate::{BitBoard, Index};
+
+use super::{
+    ChessBoard, Color, FenField, InvalidChessBoardError, InvalidFENStringError, Move, Piece,
+};
+
+/// Maximal number of pawns of one color.
+const MAX_PAWNS: usize = 8;
+
+/// Maximal number of pieces of one color.
+const MAX_PIECES: usize = 16;
+
+/// Strict FEN parsing and validation of chess positions.
+///
+/// [ChessBoard::from_fen()] stays lenient for legacy input, missing fields are defaulted
+/// and malformed ranks are accepted. [ChessBoard::from_fen_strict()] requires all six fields
+/// and a legal position, see [ChessBoard::validate()].
+///
+/// See: [ChessProgramming Forsyth-Edwards Notation](https://www.chessprogramming.org/Forsyth-Edwards_Notation)
+impl ChessBoard {
+    /// Returns ChessBoard from complete FEN definition, reporting the precise reason of a failure.
+    /// The position must pass [ChessBoard::validate()].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, FenField, InvalidChessBoardError, InvalidFENStringError};
+    ///
+    /// assert_eq!(
+    ///     ChessBoard::STANDARD,
+    ///     ChessBoard::from_fen_strict("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap()
+    /// );
+    ///
+    /// let error = |fen| ChessBoard::from_fen_strict(fen).unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -"),
+    ///     InvalidFENStringError::InvalidFieldCount(4)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidRankCount(7)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPP/RNBQKBNR w KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidRankLength(2, 7)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/44/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidRankChar(4, '4')
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNX w KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidRankChar(1, 'X')
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidField(FenField::NextMove, _)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN1 w KQkq - 0 1"),
+    ///     InvalidFENStringError::InvalidField(FenField::Castling, _)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e4 0 1"),
+    ///     InvalidFENStringError::InvalidField(FenField::EnPassant, _)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1"),
+    ///     InvalidFENStringError::InvalidField(FenField::HalfMoveClock, _)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 0"),
+    ///     InvalidFENStringError::InvalidField(FenField::FullMoveNumber, _)
+    /// ));
+    /// assert!(matches!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQQBNR w kq - 0 1"),
+    ///     InvalidFENStringError::InvalidChessBoard(InvalidChessBoardError::InvalidKingCount(Color::White, 0))
+    /// ));
+    /// assert_eq!(
+    ///     error("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e3 0 1").to_string(),
+    ///     "Invalid FEN position: Invalid en-passant target: e3"
+    /// );
+    /// ```
+    pub fn from_fen_strict(fen: &str) -> Result<Self, InvalidFENStringError> {
+        let fields: Vec<&str> = fen.split(' ').collect();
+        if fields.len() != FenField::VALUES.len() {
+            return Err(InvalidFENStringError::InvalidFieldCount(fields.len()));
+        }
+
+        let invalid_field = |field: FenField| {
+            InvalidFENStringError::InvalidField(field, fields[field as usize].to_string())
+        };
+
+        // pieces, ranks from 8 to 1
+        let ranks: Vec<&str> = fields[FenField::Pieces as usize].split('/').collect();
+        if ranks.len() != 8 {
+            return Err(InvalidFENStringError::InvalidRankCount(ranks.len()));
+        }
+
+        let mut pieces = [[BitBoard::EMPTY; Piece::VALUES.len()]; Color::VALUES.len()];
+        for (rank, notation) in (0..8).rev().zip(ranks) {
+            let mut file = 0;
+            let mut empty_fields = false;
+
+            for c in notation.chars() {
+                match c {
+                    // two adjacent numbers of empty fields are not allowed
+                    '1'..='8' if !empty_fields => {
+                        file += c as usize - '0' as usize;
+                        empty_fields = true;
+                    }
+                    _ => {
+                        let Ok((color, piece)) = Piece::from_char(c) else {
+                            return Err(InvalidFENStringError::InvalidRankChar(rank + 1, c));
+                        };
+                        if file < 8 {
+                            pieces[*color][*piece] |= Index::from_rank_and_file(rank, file);
+                        }
+                        file += 1;
+                        empty_fields = false;
+                    }
+                }
+            }
+
+            if file != 8 {
+                return Err(InvalidFENStringError::InvalidRankLength(rank + 1, file));
+            }
+        }
+
+        // next move
+        let next_move = match fields[FenField::NextMove as usize] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            _ => return Err(invalid_field(FenField::NextMove)),
+        };
+
+        // castling, every option must refer to a king and a rook
+        let castling = fields[FenField::Castling as usize];
+        let castling_count = match castling {
+            "-" => 0,
+            _ if castling.is_empty() || castling.contains('-') => {
+                return Err(invalid_field(FenField::Castling));
+            }
+            _ => castling.len(),
+        };
+        let (castling_options, castling_rooks, chess960) =
+            ChessBoard::castling_from_fen(&pieces, castling)
+                .filter(|(options, _, _)| {
+                    options.iter().flatten().filter(|o| **o).count() == castling_count
+                })
+                .ok_or_else(|| invalid_field(FenField::Castling))?;
+
+        // enPassant
+        let en_passant_target = match fields[FenField::EnPassant as usize] {
+            "-" => None,
+            notation => match Index::from_string(notation) {
+                Ok(i) if i.rank() == 2 || i.rank() == 5 => Some(i),
+                _ => return Err(invalid_field(FenField::EnPassant)),
+            },
+        };
+
+        // half move clock + full move number
+        let number = |field: FenField| {
+            let notation = fields[field as usize];
+            notation
+                .bytes()
+                .all(|c| c.is_ascii_digit())
+                .then(|| notation.parse::<usize>().ok())
+                .flatten()
+                .ok_or_else(|| invalid_field(field))
+        };
+        let half_move_clock = number(FenField::HalfMoveClock)?;
+        let full_move_number = number(FenField::FullMoveNumber)?;
+        if full_move_number == 0 {
+            return Err(invalid_field(FenField::FullMoveNumber));
+        }
+
+        let board = ChessBoard::from_parts(
+            pieces,
+            next_move,
+            castling_options,
+            castling_rooks,
+            chess960,
+            en_passant_target,
+            half_move_clock,
+            full_move_number,
+        );
+        board.validate()?;
+
+        Ok(board)
+    }
+
+    /// Checks that the board is a valid chess position:
+    /// - no field is occupied by more than one piece
+    /// - each side has exactly one king, at most 8 pawns and at most 16 pieces
+    /// - there are no pawns on the first or the last rank
+    /// - castling options refer to a king and a rook on the back rank
+    /// - en-passant target is behind a pawn which has just made a double push
+    /// - the side not to move is not in check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Color, Index, InvalidChessBoardError, Piece};
+    ///
+    /// assert!(ChessBoard::STANDARD.validate().is_ok());
+    ///
+    /// let error = |fen| ChessBoard::from_fen(fen).unwrap().validate().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     error("4k3/8/8/8/8/8/8/4K1K1 w - - 0 1"),
+    ///     InvalidChessBoardError::InvalidKingCount(Color::White, 2)
+    /// ));
+    /// assert!(matches!(
+    ///     error("4k3/8/8/8/8/8/8/3PK3 w - - 0 1"),
+    ///     InvalidChessBoardError::PawnOnBackRank(Color::White, Index::D1)
+    /// ));
+    /// assert!(matches!(
+    ///     error("4k3/8/8/8/8/8/PPPPPPPP/PPPPKPPP w - - 0 1"),
+    ///     InvalidChessBoardError::TooManyPawns(Color::White, 15)
+    /// ));
+    /// assert!(matches!(
+    ///     error("4k3/8/8/8/4P3/8/8/4K3 b - e6 0 1"),
+    ///     InvalidChessBoardError::InvalidEnPassant(Index::E6)
+    /// ));
+    /// assert!(matches!(
+    ///     error("4k2R/8/8/8/8/8/8/4K3 w - - 0 1"),
+    ///     InvalidChessBoardError::OpponentInCheck(Color::Black)
+    /// ));
+    ///
+    /// let mut board = ChessBoard::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    /// board.set_castling_option(Color::White, Piece::King, true);
+    /// assert!(matches!(
+    ///     board.validate(),
+    ///     Err(InvalidChessBoardError::InvalidCastling(Color::White, Piece::King))
+    /// ));
+    ///
+    /// assert!(ChessBoard::from_fen("4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1").unwrap().validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidChessBoardError> {
+        let mut occupied = BitBoard::EMPTY;
+        for c in Color::VALUES {
+            for p in Piece::VALUES {
+                if let Some(i) = (self.pieces[*c][*p] & occupied).bitscan() {
+                    return Err(InvalidChessBoardError::OverlappingPieces(i));
+                }
+                occupied |= self.pieces[*c][*p];
+            }
+        }
+
+        for c in Color::VALUES {
+            let kings = self.pieces[*c][*Piece::King].popcnt();
+            if kings != 1 {
+                return Err(InvalidChessBoardError::InvalidKingCount(c, kings));
+            }
+
+            let pawns = self.pieces[*c][*Piece::Pawn];
+            if pawns.popcnt() > MAX_PAWNS {
+                return Err(InvalidChessBoardError::TooManyPawns(c, pawns.popcnt()));
+            }
+
+            let count = Piece::VALUES
+                .iter()
+                .map(|p| self.pieces[*c][**p].popcnt())
+                .sum::<usize>();
+            if count > MAX_PIECES {
+                return Err(InvalidChessBoardError::TooManyPieces(c, count));
+            }
+
+            if let Some(i) = (pawns & (BitBoard::RANK_1 | BitBoard::RANK_8)).bitscan() {
+                return Err(InvalidChessBoardError::PawnOnBackRank(c, i));
+            }
+        }
+
+        // castling
+        for c in Color::VALUES {
+            let king = self.pieces[*c][*Piece::King].bitscan().unwrap();
+            let back_rank = match c {
+                Color::White => 0,
+                Color::Black => 7,
+            };
+
+            for side in [Piece::King, Piece::Queen] {
+                let Some(rook) = self.castling_rook(c, side) else {
+                    continue;
+                };
+
+                let valid = king.rank() == back_rank
+                    && rook.rank() == back_rank
+                    && self.pieces[*c][*Piece::Rook].has_bit(rook)
+                    && ChessBoard::castling_side(&Move::new(king, rook, None)) == side
+                    && (self.is_chess960()
+                        || (king.file() == 4
+                            && rook == ChessBoard::STANDARD_CASTLING_ROOKS[*c][*side]));
+
+                if !valid {
+                    return Err(InvalidChessBoardError::InvalidCastling(c, side));
+                }
+            }
+        }
+
+        // enPassant: the pawn is in front of the target, the field it came from is empty
+        if let Some(target) = self.en_passant_target {
+            let (rank, pawn, origin) = match self.next_move {
+                Color::White => (5, target.shifted_south(), target.shifted_north()),
+                Color::Black => (2, target.shifted_north(), target.shifted_south()),
+            };
+            let opponent_pawns = self.pieces[*self.next_move.opponent()][*Piece::Pawn];
+
+            let valid = target.rank() == rank
+                && !occupied.has_bit(target)
+                && origin.is_some_and(|i| !occupied.has_bit(i))
+                && pawn.is_some_and(|i| opponent_pawns.has_bit(i));
+
+            if !valid {
+                return Err(InvalidChessBoardError::InvalidEnPassant(target));
+            }
+        }
+
+        // side not to move can not be in check
+        let opponent = self.next_move.opponent();
+        let king = self.pieces[*opponent][*Piece::King].bitscan().unwrap();
+        if self.attackers_to(king, self.next_move) != BitBoard::EMPTY {
+            return Err(InvalidChessBoardError::OpponentInCheck(opponent));
+        }
+
+        Ok(())
+    }
+}
@@ -1,20 +1,45 @@
 pub use self::bitboard::BitBoard;
+pub use self::bitboard::Direction;
 pub use self::bitboard::Index;
 pub use self::bitboard::InvalidBitBoardStringError;
 pub use self::bitboard::InvalidIndexNotationError;
 pub use self::chessboard::ChessBoard;
+pub use self::chessboard::ChessBoardBuilder;
 pub use self::chessboard::Color;
+pub use self::chessboard::FenField;
+pub use self::chessboard::Game;
 pub use self::chessboard::Generator;
 pub use self::chessboard::IllegalMoveError;
+pub use self::chessboard::InvalidChessBoardError;
 pub use self::chessboard::InvalidChessBoardStringError;
 pub use self::chessboard::InvalidColorNotationError;
 pub use self::chessboard::InvalidFENStringError;
 pub use self::chessboard::InvalidMoveNotationError;
+pub use self::chessboard::InvalidPackedChessBoardError;
 pub use self::chessboard::InvalidPieceNotationError;
 pub use self::chessboard::Move;
+pub use self::chessboard::MoveGeneration;
+pub use self::chessboard::MoveKind;
+pub use self::chessboard::MoveList;
+pub use self::chessboard::Outcome;
 pub use self::chessboard::PerfT;
+pub use self::chessboard::PerfTStats;
 pub use self::chessboard::Piece;
+pub use self::chessboard::PieceValues;
+pub use self::chessboard::UndoInfo;
 pub use self::chessboard::Zobrist;
+pub use self::pgn::InvalidPGNError;
+pub use self::pgn::PgnGame;
+pub use self::pgn::PgnMove;
+pub use self::pgn::PgnReader;
+pub use self::pgn::PgnResult;
+pub use self::polyglot::InvalidPolyglotBookError;
+pub use self::polyglot::PolyglotBook;
+pub use self::polyglot::PolyglotMove;
+pub use self::uci::Uci;
 
 mod bitboard;
 mod chessboard;
+mod pgn;
+mod polyglot;
+mod uci;
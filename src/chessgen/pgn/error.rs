@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// An error returned when parsing a PGN game.
+#[derive(Debug)]
+pub enum InvalidPGNError {
+    InvalidTag(String),
+    InvalidFEN(String),
+    IllegalMove(String),
+    UnexpectedToken(String),
+    UnexpectedEnd,
+    IOError(std::io::Error),
+}
+
+impl std::error::Error for InvalidPGNError {}
+
+impl fmt::Display for InvalidPGNError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidTag(s) => write!(f, "Invalid PGN tag: '{}'", s),
+            Self::InvalidFEN(s) => write!(f, "Invalid PGN FEN tag: '{}'", s),
+            Self::IllegalMove(s) => write!(f, "Illegal PGN move: '{}'", s),
+            Self::UnexpectedToken(s) => write!(f, "Unexpected PGN token: '{}'", s),
+            Self::UnexpectedEnd => write!(f, "Unexpected end of PGN game"),
+            Self::IOError(e) => write!(f, "PGN read error: {}", e),
+        }
+    }
+}
+
+impl From<std::io::Error> for InvalidPGNError {
+    fn from(e: std::io::Error) -> Self {
+        InvalidPGNError::IOError(e)
+    }
+}
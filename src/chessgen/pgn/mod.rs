@@ -0,0 +1,11 @@
+pub use self::error::InvalidPGNError;
+pub use self::pgn::PgnGame;
+pub use self::pgn::PgnMove;
+pub use self::pgn::PgnResult;
+pub use self::reader::PgnReader;
+
+mod error;
+#[allow(clippy::module_inception)]
+mod pgn;
+mod reader;
+mod writer;
@@ -0,0 +1,494 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::{ChessBoard, Move};
+
+use super::InvalidPGNError;
+
+/// Result of a PGN game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PgnResult {
+    /// White won: `1-0`.
+    WhiteWins,
+    /// Black won: `0-1`.
+    BlackWins,
+    /// Draw: `1/2-1/2`.
+    Draw,
+    /// Game in progress, abandoned or unknown result: `*`.
+    Unknown,
+}
+
+impl PgnResult {
+    /// Creates result from PGN notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::PgnResult;
+    ///
+    /// assert_eq!(PgnResult::from_string("1-0"), Some(PgnResult::WhiteWins));
+    /// assert_eq!(PgnResult::from_string("1/2-1/2"), Some(PgnResult::Draw));
+    /// assert_eq!(PgnResult::from_string("e4"), None);
+    /// ```
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s {
+            "1-0" => Some(PgnResult::WhiteWins),
+            "0-1" => Some(PgnResult::BlackWins),
+            "1/2-1/2" => Some(PgnResult::Draw),
+            "*" => Some(PgnResult::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// Display and to_string() for a PgnResult.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::PgnResult;
+///
+/// assert_eq!(PgnResult::BlackWins.to_string(), "0-1");
+/// assert_eq!(PgnResult::Unknown.to_string(), "*");
+/// ```
+impl fmt::Display for PgnResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PgnResult::WhiteWins => write!(f, "1-0"),
+            PgnResult::BlackWins => write!(f, "0-1"),
+            PgnResult::Draw => write!(f, "1/2-1/2"),
+            PgnResult::Unknown => write!(f, "*"),
+        }
+    }
+}
+
+/// Move of a PGN game tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgnMove {
+    /// The move.
+    pub m: Move,
+    /// Comments preceding the move, used at start of a line only.
+    pub comments_before: Vec<String>,
+    /// Numeric Annotation Glyphs.
+    pub nags: Vec<u8>,
+    /// Comments following the move.
+    pub comments: Vec<String>,
+    /// Alternative lines, each one starting with an alternative to this move.
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+impl PgnMove {
+    /// Constructs a new PgnMove without annotations.
+    pub fn new(m: Move) -> Self {
+        PgnMove {
+            m,
+            comments_before: Vec::new(),
+            nags: Vec::new(),
+            comments: Vec::new(),
+            variations: Vec::new(),
+        }
+    }
+}
+
+/// PGN game.
+///
+/// See: [PGN Specification](https://www.saremba.de/chessgml/standards/pgn/pgn-complete.htm)
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, Move, PgnGame, PgnResult};
+///
+/// let game = PgnGame::from_pgn(r#"
+/// [Event "Casual game"]
+/// [White "Anderssen"]
+/// [Black "Kieseritzky"]
+/// [Result "1-0"]
+///
+/// 1. e4 e5 2. f4 {King's Gambit} exf4 3. Bc4 Qh4+ 4. Kf1 b5!? (4... d6 5. Nf3 (5. d4) 5... Qh6)
+/// 5. Bxb5 $2 Nf6 ; end of line comment
+/// 1-0
+/// "#).unwrap();
+///
+/// assert_eq!(game.tag("White"), Some("Anderssen"));
+/// assert_eq!(game.tag("Site"), None);
+/// assert_eq!(game.result, PgnResult::WhiteWins);
+/// assert_eq!(game.moves.len(), 10);
+/// assert_eq!(game.moves[2].comments, vec!["King's Gambit"]);
+/// assert_eq!(game.moves[7].nags, vec![5]);
+/// assert_eq!(game.moves[7].variations[0].len(), 3);
+/// assert_eq!(game.moves[7].variations[0][1].variations[0][0].m, Move::from_string("d2d4").unwrap());
+/// assert_eq!(game.moves[8].nags, vec![2]);
+/// assert_eq!(game.moves[9].comments, vec!["end of line comment"]);
+/// assert_eq!(
+///     game.board_at_end(),
+///     ChessBoard::from_fen("rnb1kb1r/p1pp1ppp/5n2/1B6/4Pp1q/8/PPPP2PP/RNBQ1KNR w kq - 1 6").unwrap()
+/// );
+///
+/// assert!(PgnGame::from_pgn("1. e4 e5 2. Ke3 *").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgnGame {
+    /// Tag pairs in order of appearance.
+    pub tags: Vec<(String, String)>,
+    /// Initial position, taken from the FEN tag or standard layout.
+    pub board: ChessBoard,
+    /// Main line of the game.
+    pub moves: Vec<PgnMove>,
+    /// Comments which can not be attached to any move.
+    pub comments: Vec<String>,
+    /// Game result.
+    pub result: PgnResult,
+}
+
+/// Constructs a new empty game from the standard layout.
+impl Default for PgnGame {
+    fn default() -> Self {
+        PgnGame::new(ChessBoard::STANDARD)
+    }
+}
+
+impl PgnGame {
+    /// Seven Tag Roster, tags which are always exported in this order.
+    pub const SEVEN_TAG_ROSTER: [&'static str; 7] =
+        ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+    /// Constructs a new empty game starting at a given position.
+    pub fn new(board: ChessBoard) -> Self {
+        PgnGame {
+            tags: Vec::new(),
+            board,
+            moves: Vec::new(),
+            comments: Vec::new(),
+            result: PgnResult::Unknown,
+        }
+    }
+
+    /// Returns value of a tag.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets value of a tag, replacing the existing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::PgnGame;
+    ///
+    /// let mut game = PgnGame::default();
+    /// game.set_tag("White", "Morphy");
+    /// game.set_tag("White", "Paul Morphy");
+    ///
+    /// assert_eq!(game.tag("White"), Some("Paul Morphy"));
+    /// assert_eq!(game.tags.len(), 1);
+    /// ```
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns position at the end of the main line.
+    pub fn board_at_end(&self) -> ChessBoard {
+        self.moves
+            .iter()
+            .fold(self.board, |board, m| board.apply_move(&m.m))
+    }
+
+    /// Parses one PGN game.
+    /// Every move is validated, including moves of variations.
+    /// En-passant suffix `e.p.` of a capture is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, PgnGame};
+    ///
+    /// let game = PgnGame::from_pgn("1. e4 d5 2. e5 f5 3. exf6 e.p. Nxf6 *").unwrap();
+    /// assert_eq!(game.moves.len(), 6);
+    /// assert_eq!(
+    ///     game.board_at_end(),
+    ///     ChessBoard::from_fen("rnbqkb1r/ppp1p1pp/5n2/3p4/8/8/PPPP1PPP/RNBQKBNR w KQkq - 0 4").unwrap()
+    /// );
+    ///
+    /// let game = PgnGame::from_pgn("1. e4 d5 2. e5 f5 3. exf6e.p. *").unwrap();
+    /// assert_eq!(game.moves.len(), 5);
+    /// ```
+    pub fn from_pgn(pgn: &str) -> Result<Self, InvalidPGNError> {
+        let mut parser = Parser {
+            tokens: Tokenizer {
+                chars: pgn.chars().peekable(),
+                line_start: true,
+            },
+            result: None,
+        };
+
+        parser.parse_game()
+    }
+}
+
+/// PGN Token.
+#[derive(Debug)]
+enum Token {
+    Tag(String, String),
+    Comment(String),
+    Nag(u8),
+    VariationStart,
+    VariationEnd,
+    Result(PgnResult),
+    Symbol(String),
+}
+
+/// Splits PGN text into tokens, skipping move numbers.
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line_start: bool,
+}
+
+impl Tokenizer<'_> {
+    /// Reads characters up to (excluding) a terminator.
+    fn read_until(&mut self, terminator: char) -> Result<String, InvalidPGNError> {
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some(c) if c == terminator => return Ok(s),
+                Some(c) => s.push(c),
+                None => return Err(InvalidPGNError::UnexpectedEnd),
+            }
+        }
+    }
+
+    /// Reads a tag pair, opening bracket is already consumed.
+    fn read_tag(&mut self) -> Result<Token, InvalidPGNError> {
+        let content = self.read_until(']')?;
+        let invalid = || InvalidPGNError::InvalidTag(content.clone());
+
+        let (name, value) = content.trim().split_once('"').ok_or_else(invalid)?;
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+
+        let value = value.strip_suffix('"').ok_or_else(invalid)?;
+        let mut unescaped = String::new();
+        let mut escaped = false;
+        for c in value.chars() {
+            if c == '\\' && !escaped {
+                escaped = true;
+            } else {
+                unescaped.push(c);
+                escaped = false;
+            }
+        }
+
+        Ok(Token::Tag(name.to_string(), unescaped))
+    }
+
+    /// Skips `.p.` if it follows, the `e` of an en-passant suffix `e.p.` is already consumed.
+    fn skip_en_passant_suffix(&mut self) -> bool {
+        if self.chars.clone().take(3).eq(".p.".chars()) {
+            self.chars.nth(2);
+            return true;
+        }
+        false
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, InvalidPGNError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let c = self.chars.next()?;
+            let line_start = self.line_start;
+            self.line_start = c == '\n';
+
+            match c {
+                // escape mechanism - skip the line
+                '%' if line_start => while self.chars.next_if(|c| *c != '\n').is_some() {},
+                _ if c.is_whitespace() => {}
+                '.' => {}
+                'e' if self.skip_en_passant_suffix() => {}
+                '[' => return Some(self.read_tag()),
+                '{' => {
+                    let comment = self.read_until('}');
+                    return Some(comment.map(|c| {
+                        Token::Comment(c.split_whitespace().collect::<Vec<_>>().join(" "))
+                    }));
+                }
+                ';' => {
+                    let mut comment = String::new();
+                    while let Some(c) = self.chars.next_if(|c| *c != '\n') {
+                        comment.push(c);
+                    }
+                    return Some(Ok(Token::Comment(comment.trim().to_string())));
+                }
+                '(' => return Some(Ok(Token::VariationStart)),
+                ')' => return Some(Ok(Token::VariationEnd)),
+                '$' => {
+                    let mut nag = String::new();
+                    while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit()) {
+                        nag.push(c);
+                    }
+                    return Some(match nag.parse() {
+                        Ok(n) => Ok(Token::Nag(n)),
+                        Err(_) => Err(InvalidPGNError::UnexpectedToken(format!("${}", nag))),
+                    });
+                }
+                '!' | '?' => {
+                    let mut annotation = c.to_string();
+                    while let Some(c) = self.chars.next_if(|c| *c == '!' || *c == '?') {
+                        annotation.push(c);
+                    }
+                    let nag = match annotation.as_str() {
+                        "!" => 1,
+                        "?" => 2,
+                        "!!" => 3,
+                        "??" => 4,
+                        "!?" => 5,
+                        "?!" => 6,
+                        _ => return Some(Err(InvalidPGNError::UnexpectedToken(annotation))),
+                    };
+                    return Some(Ok(Token::Nag(nag)));
+                }
+                _ => {
+                    let mut symbol = c.to_string();
+                    while let Some(c) = self
+                        .chars
+                        .next_if(|c| c.is_alphanumeric() || "_+#=:-/*".contains(*c))
+                    {
+                        symbol.push(c);
+                    }
+
+                    // en-passant suffix attached to the capture, e.g. exf6e.p.
+                    if symbol.len() > 1 && symbol.ends_with('e') && self.skip_en_passant_suffix() {
+                        symbol.pop();
+                    }
+
+                    if let Some(result) = PgnResult::from_string(&symbol) {
+                        return Some(Ok(Token::Result(result)));
+                    }
+
+                    // move number
+                    if symbol.chars().all(|c| c.is_ascii_digit()) {
+                        continue;
+                    }
+
+                    return Some(Ok(Token::Symbol(symbol)));
+                }
+            }
+        }
+    }
+}
+
+/// Builds PGN game tree from tokens.
+struct Parser<'a> {
+    tokens: Tokenizer<'a>,
+    result: Option<PgnResult>,
+}
+
+impl Parser<'_> {
+    /// Parses tags and the main line.
+    fn parse_game(&mut self) -> Result<PgnGame, InvalidPGNError> {
+        let mut game = PgnGame::default();
+
+        // tags
+        let mut token = self.tokens.next().transpose()?;
+        while let Some(Token::Tag(name, value)) = token {
+            game.tags.push((name, value));
+            token = self.tokens.next().transpose()?;
+        }
+
+        if let Some(fen) = game.tag("FEN") {
+            game.board = ChessBoard::from_fen(fen)
+                .map_err(|_| InvalidPGNError::InvalidFEN(fen.to_string()))?;
+        }
+
+        let mut comments = Vec::new();
+        game.moves = self.parse_line(game.board, token, &mut comments, false)?;
+        game.comments = comments;
+
+        game.result = match self.result {
+            Some(result) => result,
+            None => game
+                .tag("Result")
+                .and_then(PgnResult::from_string)
+                .unwrap_or(PgnResult::Unknown),
+        };
+
+        Ok(game)
+    }
+
+    /// Parses a line of moves starting at a given board.
+    /// Comments which can not be attached to any move are returned in `comments`.
+    fn parse_line(
+        &mut self,
+        board: ChessBoard,
+        first: Option<Token>,
+        comments: &mut Vec<String>,
+        is_variation: bool,
+    ) -> Result<Vec<PgnMove>, InvalidPGNError> {
+        let mut moves: Vec<PgnMove> = Vec::new();
+        let mut board = board;
+        let mut previous_board = board;
+
+        let mut token = first;
+        loop {
+            match token {
+                None if is_variation => return Err(InvalidPGNError::UnexpectedEnd),
+                None => return Ok(moves),
+                Some(Token::Result(result)) if !is_variation => {
+                    self.result = Some(result);
+                    return Ok(moves);
+                }
+                Some(Token::VariationEnd) if is_variation => return Ok(moves),
+                Some(Token::Comment(comment)) => match moves.last_mut() {
+                    Some(last) => last.comments.push(comment),
+                    None => comments.push(comment),
+                },
+                Some(Token::Nag(nag)) => match moves.last_mut() {
+                    Some(last) => last.nags.push(nag),
+                    None => return Err(InvalidPGNError::UnexpectedToken(format!("${}", nag))),
+                },
+                Some(Token::VariationStart) => {
+                    let first = self.tokens.next().transpose()?;
+                    let mut variation_comments = Vec::new();
+                    let variation =
+                        self.parse_line(previous_board, first, &mut variation_comments, true)?;
+
+                    match (moves.last_mut(), variation.is_empty()) {
+                        (Some(last), false) => last.variations.push(variation),
+                        _ => return Err(InvalidPGNError::UnexpectedToken("(".to_string())),
+                    }
+                }
+                Some(Token::Symbol(san)) => {
+                    let played = board
+                        .parse_san(&san)
+                        .ok()
+                        .and_then(|m| Some((m, board.validate_and_apply_move(&m).ok()?)));
+                    let Some((m, new_board)) = played else {
+                        return Err(InvalidPGNError::IllegalMove(san));
+                    };
+
+                    let mut pgn_move = PgnMove::new(m);
+                    if moves.is_empty() {
+                        pgn_move.comments_before.append(comments);
+                    }
+                    moves.push(pgn_move);
+
+                    previous_board = board;
+                    board = new_board;
+                }
+                Some(t) => return Err(InvalidPGNError::UnexpectedToken(format!("{:?}", t))),
+            }
+
+            token = self.tokens.next().transpose()?;
+        }
+    }
+}
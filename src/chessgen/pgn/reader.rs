@@ -0,0 +1,121 @@
+use std::io::BufRead;
+
+use super::{InvalidPGNError, PgnGame};
+
+/// Streaming reader of multi-game PGN files.
+///
+/// Games are read one by one, so large databases do not have to fit in memory.
+/// An invalid game produces an error and reading continues with the next game.
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{PgnReader, PgnResult};
+///
+/// let pgn = r#"
+/// [Event "First"]
+/// [Result "1-0"]
+///
+/// 1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7# 1-0
+///
+/// [Event "Second"]
+/// [Result "*"]
+///
+/// 1. e4 e5 2. Ke3 *
+///
+/// [Event "Third { not a comment"]
+/// [Result "1/2-1/2"]
+///
+/// 1. d4 {a comment
+/// [spanning] lines} d5 1/2-1/2
+/// "#;
+///
+/// let games: Vec<_> = PgnReader::new(pgn.as_bytes()).collect();
+///
+/// assert_eq!(games.len(), 3);
+/// assert_eq!(games[0].as_ref().unwrap().result, PgnResult::WhiteWins);
+/// assert!(games[1].is_err());
+/// assert_eq!(games[2].as_ref().unwrap().tag("Event"), Some("Third { not a comment"));
+/// assert_eq!(games[2].as_ref().unwrap().moves[0].comments, vec!["a comment [spanning] lines"]);
+/// ```
+#[derive(Debug)]
+pub struct PgnReader<R: BufRead> {
+    /// Source of PGN data.
+    reader: R,
+    /// First line of the next game, already read.
+    pending_line: Option<String>,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    /// Constructs a new PgnReader.
+    pub fn new(reader: R) -> Self {
+        PgnReader {
+            reader,
+            pending_line: None,
+        }
+    }
+
+    /// Reads text of the next game, returns None at the end of input.
+    fn read_game(&mut self) -> Result<Option<String>, InvalidPGNError> {
+        let mut text = String::new();
+        let mut has_movetext = false;
+        let mut in_comment = false;
+
+        loop {
+            let line = match self.pending_line.take() {
+                Some(line) => line,
+                None => {
+                    let mut line = String::new();
+                    if self.reader.read_line(&mut line)? == 0 {
+                        break;
+                    }
+                    line
+                }
+            };
+
+            let trimmed = line.trim();
+
+            if !in_comment && trimmed.starts_with('[') {
+                // tag of the next game
+                if has_movetext {
+                    self.pending_line = Some(line);
+                    break;
+                }
+            } else if !in_comment && (trimmed.is_empty() || trimmed.starts_with('%')) {
+                // empty line or escaped line
+            } else {
+                has_movetext = true;
+
+                // track brace comments spanning multiple lines
+                for c in trimmed.chars() {
+                    match c {
+                        '{' if !in_comment => in_comment = true,
+                        '}' if in_comment => in_comment = false,
+                        ';' if !in_comment => break,
+                        _ => {}
+                    }
+                }
+            }
+
+            text.push_str(&line);
+        }
+
+        if text.trim().is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(text))
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = Result<PgnGame, InvalidPGNError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_game() {
+            Ok(Some(text)) => Some(PgnGame::from_pgn(&text)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use crate::{ChessBoard, Color};
+
+use super::{PgnGame, PgnMove};
+
+/// Maximal length of a line of exported PGN.
+const LINE_LENGTH: usize = 80;
+
+impl PgnGame {
+    /// Returns PGN representation of the game in export format.
+    ///
+    /// Tags of the Seven Tag Roster are written first, followed by other tags.
+    /// Movetext lines are wrapped at 80 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::PgnGame;
+    ///
+    /// let pgn = r#"[Event "Casual game"]
+    /// [Site "?"]
+    /// [Date "????.??.??"]
+    /// [Round "?"]
+    /// [White "Anderssen"]
+    /// [Black "Kieseritzky \"The Elder\""]
+    /// [Result "1-0"]
+    /// [ECO "C33"]
+    ///
+    /// {King's Gambit} 1. e4 e5 2. f4 exf4 3. Bc4 Qh4+ 4. Kf1 b5 $5 (4... d6 {also
+    /// possible} 5. Nf3 (5. d4) 5... Qh6) 5. Bxb5 $2 Nf6 6. Nf3 Qh6 7. d3 Nh5 8. Nh4
+    /// Qg5 9. Nf5 c6 10. g4 Nf6 11. Rg1 cxb5 12. h4 Qg6 13. h5 Qg5 14. Qf3 Ng8 15. Bxf4
+    /// Qf6 16. Nc3 Bc5 17. Nd5 Qxb2 18. Bd6 Bxg1 19. e5 Qxa1+ 20. Ke2 Na6 21. Nxg7+ Kd8
+    /// 22. Qf6+ Nxf6 23. Be7# 1-0
+    /// "#;
+    ///
+    /// let game = PgnGame::from_pgn(pgn).unwrap();
+    ///
+    /// assert_eq!(game.to_pgn(), pgn);
+    /// assert_eq!(PgnGame::from_pgn(&game.to_pgn()).unwrap(), game);
+    /// ```
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+
+        // tags
+        for name in PgnGame::SEVEN_TAG_ROSTER {
+            let value = match name {
+                "Result" => self.result.to_string(),
+                "Date" => self.tag(name).unwrap_or("????.??.??").to_string(),
+                _ => self.tag(name).unwrap_or("?").to_string(),
+            };
+            pgn.push_str(&format_tag(name, &value));
+        }
+        for (name, value) in &self.tags {
+            if !PgnGame::SEVEN_TAG_ROSTER.contains(&name.as_str()) {
+                pgn.push_str(&format_tag(name, value));
+            }
+        }
+        pgn.push('\n');
+
+        // movetext
+        let mut tokens = Vec::new();
+        write_line(&mut tokens, &self.board, &self.moves);
+        for comment in &self.comments {
+            tokens.push(format_comment(comment));
+        }
+        tokens.push(self.result.to_string());
+
+        // wrap the lines
+        let mut line = String::new();
+        for word in tokens.iter().flat_map(|t| t.split(' ')) {
+            if !line.is_empty() && line.len() + 1 + word.len() > LINE_LENGTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        pgn.push_str(&line);
+        pgn.push('\n');
+
+        pgn
+    }
+}
+
+/// Display and to_string() for a PgnGame.
+/// Outputs PGN export format.
+impl fmt::Display for PgnGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_pgn())
+    }
+}
+
+/// Formats a tag pair.
+fn format_tag(name: &str, value: &str) -> String {
+    format!(
+        "[{} \"{}\"]\n",
+        name,
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Formats a brace comment, the comment may not contain closing brace.
+fn format_comment(comment: &str) -> String {
+    let comment = comment.replace('}', "");
+    format!(
+        "{{{}}}",
+        comment.split_whitespace().collect::<Vec<_>>().join(" ")
+    )
+}
+
+/// Writes tokens of a line of moves starting at a given board.
+fn write_line(tokens: &mut Vec<String>, board: &ChessBoard, moves: &[PgnMove]) {
+    let mut board = *board;
+    let mut needs_number = true;
+
+    for m in moves {
+        for comment in &m.comments_before {
+            tokens.push(format_comment(comment));
+            needs_number = true;
+        }
+
+        match board.next_move {
+            Color::White => tokens.push(format!("{}.", board.full_move_number)),
+            Color::Black if needs_number => tokens.push(format!("{}...", board.full_move_number)),
+            Color::Black => {}
+        }
+
+        tokens.push(board.move_to_san(&m.m));
+        for nag in &m.nags {
+            tokens.push(format!("${}", nag));
+        }
+        for comment in &m.comments {
+            tokens.push(format_comment(comment));
+        }
+        needs_number = !m.comments.is_empty();
+
+        for variation in &m.variations {
+            let start = tokens.len();
+            write_line(tokens, &board, variation);
+
+            if tokens.len() > start {
+                tokens[start].insert(0, '(');
+                if let Some(last) = tokens.last_mut() {
+                    last.push(')');
+                }
+            }
+            needs_number = true;
+        }
+
+        board = board.apply_move(&m.m);
+    }
+}
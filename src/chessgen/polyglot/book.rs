@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::path::Path;
+
+use crate::{ChessBoard, Index, Move, Piece, Zobrist};
+
+use super::InvalidPolyglotBookError;
+
+/// Size of a book entry in bytes.
+const ENTRY_SIZE: usize = 16;
+
+/// Polyglot compatible Zobrist hasher.
+const POLYGLOT: Zobrist = Zobrist::polyglot();
+
+/// Polyglot book entry.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct PolyglotEntry {
+    /// Polyglot hash of the position.
+    key: u64,
+    /// Encoded move.
+    m: u16,
+    /// Weight of the move.
+    weight: u16,
+    /// Learning data.
+    learn: u32,
+}
+
+/// Move from an opening book together with its weight.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PolyglotMove {
+    /// Book move.
+    pub m: Move,
+    /// Weight of the move, moves with higher weight should be played more often.
+    pub weight: u16,
+}
+
+/// Polyglot opening book.
+///
+/// Book consists of 16 bytes big-endian entries (key, move, weight, learn) sorted by key,
+/// where key is the [Zobrist::polyglot()] hash of a position.
+///
+/// See: [Polyglot book format](http://hgm.nubati.net/book_format.html)
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::{ChessBoard, Move, PolyglotBook, PolyglotMove};
+///
+/// let m = |s| Move::from_string(s).unwrap();
+///
+/// let mut book = PolyglotBook::new();
+/// book.insert(&ChessBoard::STANDARD, &m("d2d4"), 5);
+/// book.insert(&ChessBoard::STANDARD, &m("e2e4"), 10);
+/// book.insert(&ChessBoard::STANDARD.apply_move(&m("e2e4")), &m("c7c5"), 3);
+///
+/// let board = ChessBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+/// book.insert(&board, &m("e1g1"), 1);
+///
+/// // castling is encoded as king captures rook
+/// let bytes = book.to_bytes();
+/// assert_eq!(bytes.len(), 4 * 16);
+/// assert!(bytes.chunks(16).any(|e| e[8..10] == [0x01, 0x07]));
+///
+/// let book = PolyglotBook::from_bytes(&bytes).unwrap();
+/// assert_eq!(book.len(), 4);
+///
+/// assert_eq!(
+///     book.moves(&ChessBoard::STANDARD),
+///     vec![
+///         PolyglotMove { m: m("e2e4"), weight: 10 },
+///         PolyglotMove { m: m("d2d4"), weight: 5 },
+///     ]
+/// );
+/// assert_eq!(book.moves(&board), vec![PolyglotMove { m: m("e1g1"), weight: 1 }]);
+/// assert!(book.moves(&ChessBoard::STANDARD.apply_move(&m("d2d4"))).is_empty());
+///
+/// assert!(PolyglotBook::from_bytes(&bytes[1..]).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PolyglotBook {
+    /// Book entries sorted by key.
+    entries: Vec<PolyglotEntry>,
+}
+
+impl PolyglotBook {
+    /// Constructs a new empty book.
+    pub fn new() -> Self {
+        PolyglotBook {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads a book from a `.bin` file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, InvalidPolyglotBookError> {
+        PolyglotBook::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a book from a reader.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, InvalidPolyglotBookError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        PolyglotBook::from_bytes(&bytes)
+    }
+
+    /// Constructs a book from binary data.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidPolyglotBookError> {
+        if !bytes.len().is_multiple_of(ENTRY_SIZE) {
+            return Err(InvalidPolyglotBookError::InvalidLength(bytes.len()));
+        }
+
+        let mut entries: Vec<PolyglotEntry> = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|e| PolyglotEntry {
+                key: u64::from_be_bytes(e[0..8].try_into().unwrap()),
+                m: u16::from_be_bytes(e[8..10].try_into().unwrap()),
+                weight: u16::from_be_bytes(e[10..12].try_into().unwrap()),
+                learn: u32::from_be_bytes(e[12..16].try_into().unwrap()),
+            })
+            .collect();
+
+        // books should be sorted already, stable sort keeps the order of moves
+        entries.sort_by_key(|e| e.key);
+
+        Ok(PolyglotBook { entries })
+    }
+
+    /// Returns binary data of the book.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.entries.len() * ENTRY_SIZE);
+
+        for e in &self.entries {
+            bytes.extend_from_slice(&e.key.to_be_bytes());
+            bytes.extend_from_slice(&e.m.to_be_bytes());
+            bytes.extend_from_slice(&e.weight.to_be_bytes());
+            bytes.extend_from_slice(&e.learn.to_be_bytes());
+        }
+
+        bytes
+    }
+
+    /// Writes the book in binary format.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns count of entries of the book.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the book has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a move for a position to the book.
+    pub fn insert(&mut self, board: &ChessBoard, m: &Move, weight: u16) {
+        let key = POLYGLOT.hash(board);
+        let i = self.entries.partition_point(|e| e.key <= key);
+
+        self.entries.insert(
+            i,
+            PolyglotEntry {
+                key,
+                m: PolyglotBook::encode_move(board, m),
+                weight,
+                learn: 0,
+            },
+        );
+    }
+
+    /// Returns legal book moves for a position, ordered by weight from the highest.
+    pub fn moves(&self, board: &ChessBoard) -> Vec<PolyglotMove> {
+        let key = POLYGLOT.hash(board);
+        let start = self.entries.partition_point(|e| e.key < key);
+        let legal_moves = board.legal_moves();
+
+        let mut moves: Vec<PolyglotMove> = self.entries[start..]
+            .iter()
+            .take_while(|e| e.key == key)
+            .map(|e| PolyglotMove {
+                m: PolyglotBook::decode_move(board, e.m),
+                weight: e.weight,
+            })
+            .filter(|m| legal_moves.contains(&m.m))
+            .collect();
+
+        moves.sort_by_key(|m| Reverse(m.weight));
+        moves
+    }
+
+    /// Encodes a move into Polyglot format.
+    /// Castling is encoded as king captures rook.
+    fn encode_move(board: &ChessBoard, m: &Move) -> u16 {
+        let mut to = m.to;
+
+        if !board.is_chess960()
+            && board.pieces[*board.next_move][*Piece::King].has_bit(m.from)
+            && m.from.distance_to(m.to) == 2
+        {
+            let rook_file = if m.to.file() > m.from.file() { 7 } else { 0 };
+            to = Index::from_rank_and_file(m.from.rank(), rook_file);
+        }
+
+        let promotion = match m.promotion {
+            Some(Piece::Knight) => 1,
+            Some(Piece::Bishop) => 2,
+            Some(Piece::Rook) => 3,
+            Some(Piece::Queen) => 4,
+            _ => 0,
+        };
+
+        (to.file() | to.rank() << 3 | m.from.file() << 6 | m.from.rank() << 9 | promotion << 12)
+            as u16
+    }
+
+    /// Decodes a move from Polyglot format.
+    fn decode_move(board: &ChessBoard, m: u16) -> Move {
+        let m = m as usize;
+        let from = Index::from_rank_and_file((m >> 9) & 7, (m >> 6) & 7);
+        let mut to = Index::from_rank_and_file((m >> 3) & 7, m & 7);
+
+        let promotion = match (m >> 12) & 7 {
+            1 => Some(Piece::Knight),
+            2 => Some(Piece::Bishop),
+            3 => Some(Piece::Rook),
+            4 => Some(Piece::Queen),
+            _ => None,
+        };
+
+        // castling is encoded as king captures rook, as in Chess960
+        if !board.is_chess960()
+            && board.pieces[*board.next_move][*Piece::King].has_bit(from)
+            && board.pieces[*board.next_move][*Piece::Rook].has_bit(to)
+        {
+            let king_file = if to.file() > from.file() { 6 } else { 2 };
+            to = Index::from_rank_and_file(from.rank(), king_file);
+        }
+
+        Move::new(from, to, promotion)
+    }
+}
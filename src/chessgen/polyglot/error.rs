@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// An error returned when reading a Polyglot opening book.
+#[derive(Debug)]
+pub enum InvalidPolyglotBookError {
+    InvalidLength(usize),
+    IOError(std::io::Error),
+}
+
+impl std::error::Error for InvalidPolyglotBookError {}
+
+impl fmt::Display for InvalidPolyglotBookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength(n) => write!(
+                f,
+                "Invalid Polyglot book length: {} bytes is not a multiple of entry size",
+                n
+            ),
+            Self::IOError(e) => write!(f, "Polyglot book read error: {}", e),
+        }
+    }
+}
+
+impl From<std::io::Error> for InvalidPolyglotBookError {
+    fn from(e: std::io::Error) -> Self {
+        InvalidPolyglotBookError::IOError(e)
+    }
+}
@@ -0,0 +1,6 @@
+pub use self::book::PolyglotBook;
+pub use self::book::PolyglotMove;
+pub use self::error::InvalidPolyglotBookError;
+
+mod book;
+mod error;
@@ -0,0 +1,4 @@
+pub use self::uci::Uci;
+
+#[allow(clippy::module_inception)]
+mod uci;
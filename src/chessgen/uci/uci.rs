@@ -0,0 +1,403 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{ChessBoard, Move, PerfT};
+
+/// Keywords of the `go` command.
+const GO_KEYWORDS: [&str; 12] = [
+    "searchmoves",
+    "ponder",
+    "wtime",
+    "btime",
+    "winc",
+    "binc",
+    "movestogo",
+    "depth",
+    "nodes",
+    "mate",
+    "movetime",
+    "infinite",
+];
+
+/// UCI (Universal Chess Interface) protocol front-end.
+///
+/// Keeps the current position set up by GUI and answers commands
+/// using the moves generator and [PerfT].
+/// There is no search yet, `go` answers with the first legal move.
+///
+/// See: [ChessProgramming UCI](https://www.chessprogramming.org/UCI)
+///
+/// # Examples
+///
+/// ```
+/// use chessgen::Uci;
+///
+/// let input = "\
+/// isready
+/// position startpos moves e2e4 e7e5 g1f3
+/// go perft 1
+/// position fen 7k/8/8/8/8/8/8/K6R b - - 0 1
+/// go depth 10
+/// quit
+/// ";
+///
+/// let mut output = Vec::new();
+/// Uci::new().run(input.as_bytes(), &mut output).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "\
+/// readyok
+/// f8a3: 1
+/// f8b4: 1
+/// f8c5: 1
+/// f8d6: 1
+/// f8e7: 1
+/// d8h4: 1
+/// d8g5: 1
+/// d8f6: 1
+/// d8e7: 1
+/// a7a5: 1
+/// a7a6: 1
+/// b7b5: 1
+/// b7b6: 1
+/// c7c5: 1
+/// c7c6: 1
+/// d7d5: 1
+/// d7d6: 1
+/// f7f5: 1
+/// f7f6: 1
+/// g7g5: 1
+/// g7g6: 1
+/// h7h5: 1
+/// h7h6: 1
+/// b8a6: 1
+/// b8c6: 1
+/// g8f6: 1
+/// g8h6: 1
+/// g8e7: 1
+/// e8e7: 1
+///
+/// Nodes searched: 29
+///
+/// bestmove h8g7
+/// "
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Uci {
+    /// Current position.
+    board: ChessBoard,
+    /// Size of PerfT cache in megabytes.
+    hash_size: usize,
+    /// PerfT checker, allocated on demand.
+    perft: Option<PerfT>,
+    /// Best move to be reported when an infinite search is stopped.
+    pending_best_move: Option<String>,
+    /// Chess960 mode, castling moves are sent as king captures rook.
+    chess960: bool,
+}
+
+/// Constructs a new UCI front-end.
+impl Default for Uci {
+    fn default() -> Self {
+        Uci::new()
+    }
+}
+
+impl Uci {
+    /// Default size of PerfT cache in megabytes.
+    pub const DEFAULT_HASH_SIZE: usize = 16;
+    /// Maximal size of PerfT cache in megabytes.
+    pub const MAX_HASH_SIZE: usize = 65536;
+
+    /// Constructs a new UCI front-end set to the standard layout.
+    pub fn new() -> Self {
+        Uci {
+            board: ChessBoard::STANDARD,
+            hash_size: Uci::DEFAULT_HASH_SIZE,
+            perft: None,
+            pending_best_move: None,
+            chess960: false,
+        }
+    }
+
+    /// Returns the current position.
+    pub fn board(&self) -> &ChessBoard {
+        &self.board
+    }
+
+    /// Reads commands from input until `quit` or end of input.
+    /// Responses are written to output and flushed after each command.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            let running = self.command(&line?, &mut output)?;
+            output.flush()?;
+
+            if !running {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Executes a single command.
+    /// Returns false if the command was `quit`.
+    ///
+    /// Unknown commands and invalid arguments are reported by `info string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::{ChessBoard, Uci};
+    ///
+    /// let mut uci = Uci::new();
+    /// let mut output = Vec::new();
+    ///
+    /// assert!(uci.command("position startpos moves e2e4 e7e5", &mut output).unwrap());
+    /// assert_eq!(uci.board().to_fen(), "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2");
+    ///
+    /// // position is set up to the first illegal move
+    /// assert!(uci.command("position startpos moves e2e4 e2e4", &mut output).unwrap());
+    /// assert_eq!(String::from_utf8(output).unwrap(), "info string Invalid move: 'e2e4'\n");
+    /// assert_eq!(uci.board().to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+    ///
+    /// assert!(!uci.command("quit", &mut Vec::new()).unwrap());
+    /// ```
+    pub fn command<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = tokens.split_first() else {
+            return Ok(true);
+        };
+
+        match command {
+            "uci" => {
+                writeln!(output, "id name chessgen {}", env!("CARGO_PKG_VERSION"))?;
+                writeln!(output, "id author Dusan Saiko")?;
+                writeln!(
+                    output,
+                    "option name Hash type spin default {} min 1 max {}",
+                    Uci::DEFAULT_HASH_SIZE,
+                    Uci::MAX_HASH_SIZE
+                )?;
+                writeln!(output, "option name Clear Hash type button")?;
+                writeln!(output, "option name UCI_Chess960 type check default false")?;
+                writeln!(output, "uciok")?;
+            }
+            "isready" => writeln!(output, "readyok")?,
+            "debug" | "register" => {}
+            "ucinewgame" => {
+                self.board = ChessBoard::STANDARD;
+                self.perft = None;
+            }
+            "setoption" => self.set_option(args, output)?,
+            "position" => self.position(args, output)?,
+            "go" => self.go(args, output)?,
+            "stop" | "ponderhit" => {
+                if let Some(best_move) = self.pending_best_move.take() {
+                    writeln!(output, "bestmove {}", best_move)?;
+                }
+            }
+            "d" => {
+                write!(output, "{}", self.board)?;
+                writeln!(output, "Fen: {}", self.board.to_fen())?;
+            }
+            "quit" => return Ok(false),
+            _ => writeln!(output, "info string Unknown command: '{}'", line.trim())?,
+        }
+
+        Ok(true)
+    }
+
+    /// Handles `setoption name <id> [value <x>]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Uci;
+    ///
+    /// let mut uci = Uci::new();
+    /// let mut output = Vec::new();
+    ///
+    /// uci.command("setoption name Hash value 1", &mut output).unwrap();
+    /// uci.command("setoption name Clear Hash", &mut output).unwrap();
+    /// uci.command("setoption name Hash value lots", &mut output).unwrap();
+    /// uci.command("setoption name Threads value 4", &mut output).unwrap();
+    ///
+    /// uci.command("setoption name UCI_Chess960 value true", &mut Vec::new()).unwrap();
+    /// uci.command("position fen r3k2r/8/8/8/8/8/8/1R2K1R1 w GBha - 0 1 moves e1g1", &mut Vec::new()).unwrap();
+    /// assert_eq!(uci.board().to_shredder_fen(), "r3k2r/8/8/8/8/8/8/1R3RK1 b ha - 1 1");
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "info string Invalid value of option Hash: 'lots'\ninfo string Unknown option: 'Threads'\n"
+    /// );
+    /// ```
+    fn set_option<W: Write>(&mut self, args: &[&str], output: &mut W) -> io::Result<()> {
+        let value_index = args
+            .iter()
+            .position(|a| *a == "value")
+            .unwrap_or(args.len());
+        let name = args[..value_index]
+            .iter()
+            .skip_while(|a| **a == "name")
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let value = args.get(value_index + 1..).unwrap_or_default().join(" ");
+
+        match name.to_lowercase().as_str() {
+            "hash" => match value.parse::<usize>() {
+                Ok(size) => {
+                    self.hash_size = size.clamp(1, Uci::MAX_HASH_SIZE);
+                    self.perft = None;
+                }
+                Err(_) => writeln!(
+                    output,
+                    "info string Invalid value of option Hash: '{}'",
+                    value
+                )?,
+            },
+            "clear hash" => self.perft = None,
+            "uci_chess960" => match value.as_str() {
+                "true" => self.chess960 = true,
+                "false" => self.chess960 = false,
+                _ => writeln!(
+                    output,
+                    "info string Invalid value of option UCI_Chess960: '{}'",
+                    value
+                )?,
+            },
+            _ => writeln!(output, "info string Unknown option: '{}'", name)?,
+        }
+
+        Ok(())
+    }
+
+    /// Handles `position [startpos | fen <fen>] [moves <move1> ... <movei>]`.
+    fn position<W: Write>(&mut self, args: &[&str], output: &mut W) -> io::Result<()> {
+        let moves_index = args
+            .iter()
+            .position(|a| *a == "moves")
+            .unwrap_or(args.len());
+
+        let board = match args[..moves_index] {
+            ["startpos"] => ChessBoard::STANDARD,
+            ["fen", ref fen @ ..] => match ChessBoard::from_fen(&fen.join(" ")) {
+                Ok(board) => board,
+                Err(e) => return writeln!(output, "info string {}", e),
+            },
+            _ => {
+                return writeln!(output, "info string Invalid position: '{}'", args.join(" "));
+            }
+        };
+
+        self.board = if self.chess960 {
+            board.to_chess960()
+        } else {
+            board
+        };
+        for m in args.iter().skip(moves_index + 1) {
+            match Move::from_string(m) {
+                Ok(m) => match self.board.validate_and_apply_move(&m) {
+                    Ok(board) => self.board = board,
+                    Err(e) => return writeln!(output, "info string {}", e),
+                },
+                Err(e) => return writeln!(output, "info string {}", e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles `go perft <depth>` and search commands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessgen::Uci;
+    ///
+    /// let mut uci = Uci::new();
+    /// let mut output = Vec::new();
+    ///
+    /// uci.command("go wtime 300000 btime 300000 searchmoves d2d4 g1f3", &mut output).unwrap();
+    /// uci.command("go infinite", &mut output).unwrap();
+    /// assert_eq!(String::from_utf8(output.clone()).unwrap(), "bestmove d2d4\n");
+    ///
+    /// uci.command("stop", &mut output).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "bestmove d2d4\nbestmove a2a3\n");
+    ///
+    /// let mut output = Vec::new();
+    /// uci.command("position fen 7k/8/8/8/8/8/7r/K5q1 w - - 0 1", &mut output).unwrap();
+    /// uci.command("go movetime 1000", &mut output).unwrap();
+    /// uci.command("go perft 3", &mut output).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "bestmove 0000\n\nNodes searched: 0\n\n");
+    /// ```
+    fn go<W: Write>(&mut self, args: &[&str], output: &mut W) -> io::Result<()> {
+        if let ["perft", depth, ..] = args {
+            return match depth.parse() {
+                Ok(depth) => self.perft(depth, output),
+                Err(_) => writeln!(output, "info string Invalid depth: '{}'", depth),
+            };
+        }
+
+        let mut search_moves = Vec::new();
+        let mut infinite = false;
+
+        let mut i = 0;
+        while i < args.len() {
+            match args[i] {
+                "searchmoves" => {
+                    while let Some(m) = args.get(i + 1).filter(|a| !GO_KEYWORDS.contains(a)) {
+                        match Move::from_string(m) {
+                            Ok(m) => search_moves.push(m),
+                            Err(e) => writeln!(output, "info string {}", e)?,
+                        }
+                        i += 1;
+                    }
+                }
+                "infinite" | "ponder" => infinite = true,
+                _ => {}
+            }
+            i += 1;
+        }
+
+        let best_move = self
+            .board
+            .legal_moves()
+            .into_iter()
+            .find(|m| search_moves.is_empty() || search_moves.contains(m))
+            .map_or("0000".to_string(), |m| m.to_string());
+
+        if infinite {
+            self.pending_best_move = Some(best_move);
+        } else {
+            writeln!(output, "bestmove {}", best_move)?;
+        }
+
+        Ok(())
+    }
+
+    /// Outputs count of nodes for each legal move at the given depth.
+    fn perft<W: Write>(&mut self, depth: usize, output: &mut W) -> io::Result<()> {
+        let board = self.board;
+        let hash_size = self.hash_size;
+        let perft = self.perft.get_or_insert_with(|| {
+            let entries = hash_size * 1024 * 1024 / PerfT::CACHE_ENTRY_SIZE;
+            // cache is indexed by a bit mask of the hash
+            PerfT::new(1 << entries.ilog2())
+        });
+
+        let mut nodes = if depth == 0 { 1 } else { 0 };
+        for (m, count) in perft.perft_divide(&board, depth) {
+            writeln!(output, "{}: {}", m, count)?;
+            nodes += count;
+        }
+
+        writeln!(output)?;
+        writeln!(output, "Nodes searched: {}", nodes)?;
+        writeln!(output)?;
+
+        Ok(())
+    }
+}
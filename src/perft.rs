@@ -6,7 +6,12 @@ const CACHE_SIZE: usize = 64 * 1024 * 1024;
 
 /// Run PerfT at specific board and depth.
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    let divide = args.iter().any(|a| a == "--divide");
+    let stats = args.iter().any(|a| a == "--stats");
+    let bench = args.iter().any(|a| a == "--bench");
+    args.retain(|a| a != "--divide" && a != "--stats" && a != "--bench");
+
     let mut depth = 7usize;
     let mut fen = ChessBoard::STANDARD_BOARD_FEN.to_string();
 
@@ -17,6 +22,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
         println!("usage: [DEPTH]        - running standard layout perft for the given depth");
         println!("usage: [DEPTH] [FEN]  - running custom board layout perft for the given depth");
+        println!("usage: --divide       - printing count for each move of the board");
+        println!("usage: --stats        - printing captures, checks, ... for each depth");
+        println!("usage: --bench        - comparing uncached copy-make and make/unmake perft");
         println!();
     }
 
@@ -28,10 +36,73 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         fen = args[2].to_string();
     }
 
-    let board = ChessBoard::from_fen(&fen)?;
+    let mut board = ChessBoard::from_fen(&fen)?;
 
+    let perft = PerfT::new(CACHE_SIZE);
     let start = Instant::now();
-    let count = PerfT::new(CACHE_SIZE).perft_n(&board, depth);
+    let count = if divide && depth > 0 {
+        let mut count = 0;
+        for (m, n) in perft.perft_divide(&board, depth) {
+            println!("{}: {}", m, n);
+            count += n;
+        }
+        println!();
+        println!("Nodes searched: {}", count);
+        println!();
+        count
+    } else if stats {
+        let stats = perft.perft_stats(&board, depth);
+        println!(
+            "{:>5} {:>15} {:>12} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "depth",
+            "nodes",
+            "captures",
+            "e.p.",
+            "castles",
+            "promotions",
+            "checks",
+            "disc.",
+            "double",
+            "mates"
+        );
+        for (d, s) in stats.iter().enumerate() {
+            println!(
+                "{:>5} {:>15} {:>12} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                d + 1,
+                s.nodes,
+                s.captures,
+                s.en_passants,
+                s.castles,
+                s.promotions,
+                s.checks,
+                s.discovered_checks,
+                s.double_checks,
+                s.checkmates
+            );
+        }
+        println!();
+        stats.last().map_or(1, |s| s.nodes)
+    } else if bench {
+        let start = Instant::now();
+        let count = perft_copy_make(&board, depth);
+        println!(
+            "copy-make:   {} in {:?}",
+            humanize_number(count),
+            start.elapsed()
+        );
+
+        let start = Instant::now();
+        let count = perft_make_unmake(&mut board, depth);
+        println!(
+            "make/unmake: {} in {:?}",
+            humanize_number(count),
+            start.elapsed()
+        );
+        println!();
+        count
+    } else {
+        perft.perft_n(&board, depth)
+    };
     let duration = start.elapsed();
 
     println!("perfT finished:");
@@ -43,6 +114,43 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Uncached single-threaded perft copying the board for each move.
+fn perft_copy_make(board: &ChessBoard, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = board.legal_moves();
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    moves
+        .iter()
+        .map(|m| perft_copy_make(&board.apply_move(m), depth - 1))
+        .sum()
+}
+
+/// Uncached single-threaded perft making and unmaking moves on one board.
+fn perft_make_unmake(board: &mut ChessBoard, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = board.legal_moves();
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    let mut count = 0;
+    for m in &moves {
+        let undo = board.make_move(m);
+        count += perft_make_unmake(board, depth - 1);
+        board.unmake_move(m, undo);
+    }
+    count
+}
+
 /// Helper to output number with thousands separator.
 fn humanize_number(n: u64) -> String {
     let mut s = String::new();
@@ -0,0 +1,8 @@
+use std::io;
+
+use chessgen::Uci;
+
+/// Run UCI protocol over standard input and output.
+fn main() -> io::Result<()> {
+    Uci::new().run(io::stdin().lock(), io::stdout().lock())
+}